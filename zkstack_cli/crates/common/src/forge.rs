@@ -17,7 +17,7 @@ use xshell::{cmd, Shell};
 
 use crate::{
     cmd::{Cmd, CmdResult},
-    ethereum::create_ethers_client,
+    ethereum::{create_ethers_client, get_ethers_provider},
 };
 
 /// Forge is a wrapper around the forge binary.
@@ -196,6 +196,26 @@ impl ForgeScript {
         self.private_key().map(|k| k.address())
     }
 
+    pub fn min_balance(&self) -> Option<U256> {
+        self.args.min_balance
+    }
+
+    pub fn non_interactive(&self) -> bool {
+        self.args.non_interactive
+    }
+
+    pub fn ignore_balance_check(&self) -> bool {
+        self.args.ignore_balance_check
+    }
+
+    pub async fn get_chain_id(&self) -> anyhow::Result<Option<u64>> {
+        let Some(rpc_url) = self.rpc_url() else {
+            return Ok(None);
+        };
+        let provider = get_ethers_provider(&rpc_url)?;
+        Ok(Some(provider.get_chainid().await?.as_u64()))
+    }
+
     pub async fn get_the_balance(&self) -> anyhow::Result<Option<U256>> {
         let Some(rpc_url) = self.rpc_url() else {
             return Ok(None);
@@ -314,6 +334,16 @@ pub struct ForgeScriptArgs {
     pub resume: bool,
     #[clap(long)]
     pub zksync: bool,
+    /// Minimum deployer balance in ETH required to run the script without a prompt.
+    /// Defaults to the L1 network's threshold.
+    #[clap(long, value_parser = parse_ether_amount)]
+    pub min_balance: Option<U256>,
+    /// Fail instead of prompting when the deployer balance is insufficient
+    #[clap(long)]
+    pub non_interactive: bool,
+    /// Proceed without prompting when the deployer balance is insufficient
+    #[clap(long)]
+    pub ignore_balance_check: bool,
     /// List of additional arguments that can be passed through the CLI.
     ///
     /// e.g.: `zkstack init -a --private-key=<PRIVATE_KEY>`
@@ -322,6 +352,10 @@ pub struct ForgeScriptArgs {
     additional_args: Vec<String>,
 }
 
+fn parse_ether_amount(value: &str) -> anyhow::Result<U256> {
+    Ok(ethers::utils::parse_ether(value)?)
+}
+
 impl ForgeScriptArgs {
     /// Build the forge script command arguments.
     pub fn build(&mut self) -> Vec<String> {
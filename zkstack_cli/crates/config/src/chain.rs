@@ -184,6 +184,15 @@ impl ChainConfig {
         SecretsConfig::read(self.get_shell(), &self.path_to_secrets_config()).await
     }
 
+    pub async fn get_external_node_secrets_config(&self) -> anyhow::Result<SecretsConfig> {
+        let path = self
+            .external_node_config_path
+            .as_ref()
+            .context("External node config path is not set")?
+            .join(SECRETS_FILE);
+        SecretsConfig::read(self.get_shell(), &path).await
+    }
+
     pub fn get_gateway_config(&self) -> anyhow::Result<GatewayConfig> {
         GatewayConfig::read_with_base_path(self.get_shell(), &self.configs)
     }
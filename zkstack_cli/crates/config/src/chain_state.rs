@@ -0,0 +1,40 @@
+use std::path::Path;
+
+use serde::{Deserialize, Serialize};
+use xshell::Shell;
+use zksync_basic_types::Address;
+
+use crate::{
+    consts::CHAIN_STATE_FILE,
+    traits::{FileConfigTrait, FileConfigWithDefaultName, ReadConfigWithBasePath},
+};
+
+/// L1 state of the chain cached by the CLI, so that repeated commands don't have to query it again.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ChainStateCache {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub accepted_admin: Option<AcceptedAdmin>,
+}
+
+/// Chain admin contract that has accepted the admin role of the diamond proxy.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AcceptedAdmin {
+    pub diamond_proxy: Address,
+    pub chain_admin: Address,
+}
+
+impl FileConfigWithDefaultName for ChainStateCache {
+    const FILE_NAME: &'static str = CHAIN_STATE_FILE;
+}
+
+impl FileConfigTrait for ChainStateCache {}
+
+impl ChainStateCache {
+    /// Reads the cache from the chain configs directory, or returns an empty one if it wasn't saved yet.
+    pub fn read_or_default(shell: &Shell, base_path: impl AsRef<Path>) -> anyhow::Result<Self> {
+        if !shell.path_exists(Self::get_path_with_base_path(&base_path)) {
+            return Ok(Self::default());
+        }
+        Self::read_with_base_path(shell, base_path)
+    }
+}
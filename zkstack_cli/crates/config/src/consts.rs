@@ -21,6 +21,8 @@ pub(crate) const INITIAL_DEPLOYMENT_FILE: &str = "initial_deployments.yaml";
 pub(crate) const ERC20_DEPLOYMENT_FILE: &str = "erc20_deployments.yaml";
 /// Name of the contracts file
 pub const CONTRACTS_FILE: &str = "contracts.yaml";
+/// Name of the file with the cached L1 state of the chain
+pub const CHAIN_STATE_FILE: &str = "chain_state.json";
 /// Name of the gateway contracts file
 pub const GATEWAY_FILE: &str = "gateway.yaml";
 /// Name of the gateway contracts file
@@ -76,6 +76,9 @@ pub struct InitialDeploymentConfig {
     pub validator_timelock_execution_delay: u64,
     pub token_weth_address: Address,
     pub bridgehub_create_new_chain_salt: u64,
+    /// Avail L1 DA validator to use instead of the builtin address for the L1 network.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub avail_l1_da_validator_addr: Option<Address>,
 }
 
 impl Default for InitialDeploymentConfig {
@@ -98,10 +101,19 @@ impl Default for InitialDeploymentConfig {
             // toml crate u64 support is backed by i64 implementation
             // https://github.com/toml-rs/toml/issues/705
             bridgehub_create_new_chain_salt: rand::thread_rng().gen_range(0..=i64::MAX) as u64,
+            avail_l1_da_validator_addr: None,
         }
     }
 }
 
+impl InitialDeploymentConfig {
+    /// Avail L1 DA validator to deploy: the configured one, or the builtin one for `l1_network`.
+    pub fn avail_l1_da_validator_addr_for(&self, l1_network: L1Network) -> Option<Address> {
+        self.avail_l1_da_validator_addr
+            .or_else(|| l1_network.avail_l1_da_validator_addr())
+    }
+}
+
 impl FileConfigWithDefaultName for InitialDeploymentConfig {
     const FILE_NAME: &'static str = INITIAL_DEPLOYMENT_FILE;
 }
@@ -215,7 +227,8 @@ impl DeployL1Config {
                 priority_tx_max_gas_limit: initial_deployment_config.priority_tx_max_gas_limit,
                 validator_timelock_execution_delay: initial_deployment_config
                     .validator_timelock_execution_delay,
-                avail_l1_da_validator_addr: l1_network.avail_l1_da_validator_addr(),
+                avail_l1_da_validator_addr: initial_deployment_config
+                    .avail_l1_da_validator_addr_for(l1_network),
             },
             tokens: TokensDeployL1Config {
                 token_weth_address: initial_deployment_config.token_weth_address,
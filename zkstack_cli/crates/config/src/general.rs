@@ -6,7 +6,7 @@ use zkstack_cli_common::yaml::merge_yaml;
 use zksync_basic_types::pubdata_da::PubdataSendingMode;
 
 use crate::{
-    consensus::{ConsensusConfigPatch, ConsensusGenesisSpecs},
+    consensus::{ConsensusConfigPatch, ConsensusGenesisSpecs, Weighted},
     da::AvailConfig,
     raw::{PatchedConfig, RawConfig},
     ChainConfig, ObjectStoreConfig, ObjectStoreMode,
@@ -118,6 +118,19 @@ impl GeneralConfig {
     pub fn raw_consensus_genesis_spec(&self) -> Option<&serde_yaml::Value> {
         self.0.get_raw("consensus.genesis_spec")
     }
+
+    pub fn consensus_validators(&self) -> anyhow::Result<Vec<Weighted>> {
+        self.0.get("consensus.genesis_spec.validators")
+    }
+
+    pub fn consensus_leader(&self) -> anyhow::Result<String> {
+        self.0.get("consensus.genesis_spec.leader")
+    }
+
+    /// Returns the raw value of a param at the specified dot-separated path, if it's present.
+    pub fn get_raw(&self, path: &str) -> Option<&serde_yaml::Value> {
+        self.0.get_raw(path)
+    }
 }
 
 #[derive(Debug)]
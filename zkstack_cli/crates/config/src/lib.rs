@@ -1,11 +1,12 @@
 pub use crate::{
-    apps::*, chain::*, consensus::*, consts::*, contracts::*, ecosystem::*, en::*, file_config::*,
-    gateway::*, general::*, genesis::*, manipulations::*, object_store::*, secrets::*,
-    source_files::*, wallet_creation::*, wallets::*, zkstack_config::*,
+    apps::*, chain::*, chain_state::*, consensus::*, consts::*, contracts::*, ecosystem::*, en::*,
+    file_config::*, gateway::*, general::*, genesis::*, manipulations::*, object_store::*,
+    secrets::*, source_files::*, wallet_creation::*, wallets::*, zkstack_config::*,
 };
 
 mod apps;
 mod chain;
+mod chain_state;
 mod consensus;
 mod consts;
 mod contracts;
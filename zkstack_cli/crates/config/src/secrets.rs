@@ -61,6 +61,10 @@ impl SecretsConfig {
         self.0.get("consensus.node_key")
     }
 
+    pub fn raw_consensus_validator_key(&self) -> anyhow::Result<String> {
+        self.0.get("consensus.validator_key")
+    }
+
     pub fn patched(self) -> SecretsConfigPatch {
         SecretsConfigPatch(self.0.patched())
     }
@@ -109,6 +113,10 @@ impl SecretsConfigPatch {
             .insert("consensus.node_key", consensus_keys.node_secret)
     }
 
+    pub fn set_consensus_validator_key(&mut self, raw_key: &str) -> anyhow::Result<()> {
+        self.0.insert("consensus.validator_key", raw_key)
+    }
+
     pub fn set_consensus_node_key(&mut self, raw_key: &str) -> anyhow::Result<()> {
         self.0.insert("consensus.node_key", raw_key)
     }
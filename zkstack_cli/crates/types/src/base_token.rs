@@ -1,6 +1,12 @@
-use ethers::types::Address;
+use ethers::types::{Address, H160};
 use serde::{Deserialize, Serialize};
 
+/// Address used by the contracts to denote ETH as the base token.
+pub const ETH_ADDRESS: Address = H160([
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x01,
+]);
+
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
 pub struct BaseToken {
     pub address: Address,
@@ -14,7 +20,7 @@ impl BaseToken {
         Self {
             nominator: 1,
             denominator: 1,
-            address: Address::from_low_u64_be(1),
+            address: ETH_ADDRESS,
         }
     }
 }
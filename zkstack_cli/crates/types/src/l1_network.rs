@@ -1,9 +1,16 @@
-use std::str::FromStr;
+use std::{ffi::OsStr, str::FromStr, time::Duration};
 
-use clap::ValueEnum;
-use ethers::types::Address;
+use clap::{
+    builder::{PossibleValue, TypedValueParser},
+    error::ErrorKind,
+    Arg, Command, ValueEnum,
+};
+use ethers::{
+    types::{Address, U256},
+    utils::WEI_IN_ETHER,
+};
 use serde::{Deserialize, Serialize};
-use strum::EnumIter;
+use strum::{EnumIter, IntoEnumIterator};
 
 #[derive(
     Copy,
@@ -20,6 +27,7 @@ use strum::EnumIter;
     EnumIter,
     strum::Display,
 )]
+#[non_exhaustive]
 pub enum L1Network {
     #[default]
     Localhost,
@@ -28,6 +36,82 @@ pub enum L1Network {
     Mainnet,
 }
 
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+#[error(
+    "unknown L1 network `{input}`, expected one of {}, or a known chain ID",
+    valid_l1_network_names()
+)]
+pub struct ParseL1NetworkError {
+    input: String,
+}
+
+fn valid_l1_network_names() -> String {
+    L1Network::iter()
+        .filter_map(|network| network.to_possible_value())
+        .map(|value| value.get_name().to_owned())
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+/// Parses network names case-insensitively, treating `_` and `-` the same, as well as bare chain IDs.
+/// Unlike the derived `ValueEnum` parser, this accepts e.g. `Sepolia`, `MAINNET` or `11155111`.
+impl FromStr for L1Network {
+    type Err = ParseL1NetworkError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let input = s.trim();
+        let network = if let Ok(chain_id) = input.parse::<u64>() {
+            Self::from_chain_id(chain_id)
+        } else {
+            let normalized = input.to_lowercase().replace('_', "-");
+            Self::iter().find(|network| {
+                network
+                    .to_possible_value()
+                    .is_some_and(|value| value.matches(&normalized, false))
+            })
+        };
+        network.ok_or_else(|| ParseL1NetworkError {
+            input: input.to_owned(),
+        })
+    }
+}
+
+/// Clap value parser for [`L1Network`] that accepts everything [`L1Network::from_str()`] does,
+/// while still reporting the network names as possible values for help and shell completions.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct L1NetworkValueParser;
+
+impl TypedValueParser for L1NetworkValueParser {
+    type Value = L1Network;
+
+    fn parse_ref(
+        &self,
+        cmd: &Command,
+        arg: Option<&Arg>,
+        value: &OsStr,
+    ) -> Result<Self::Value, clap::Error> {
+        let value = value
+            .to_str()
+            .ok_or_else(|| clap::Error::new(ErrorKind::InvalidUtf8).with_cmd(cmd))?;
+        value.parse().map_err(|err: ParseL1NetworkError| {
+            let arg = arg.map_or_else(|| "...".to_owned(), ToString::to_string);
+            clap::Error::raw(
+                ErrorKind::InvalidValue,
+                format!("invalid value '{value}' for '{arg}': {err}\n"),
+            )
+            .with_cmd(cmd)
+        })
+    }
+
+    fn possible_values(&self) -> Option<Box<dyn Iterator<Item = PossibleValue> + '_>> {
+        Some(Box::new(
+            L1Network::value_variants()
+                .iter()
+                .filter_map(ValueEnum::to_possible_value),
+        ))
+    }
+}
+
 impl L1Network {
     #[must_use]
     pub fn chain_id(&self) -> u64 {
@@ -39,6 +123,55 @@ impl L1Network {
         }
     }
 
+    /// Expected interval between L1 blocks.
+    #[must_use]
+    pub fn block_time(&self) -> Duration {
+        match self {
+            // Matches `--dev.block-time` of the local reth node in docker-compose.
+            L1Network::Localhost => Duration::from_millis(300),
+            L1Network::Sepolia | L1Network::Holesky | L1Network::Mainnet => Duration::from_secs(12),
+        }
+    }
+
+    /// Whether funds on this network have no real value, e.g. to gate irreversible actions.
+    #[must_use]
+    pub fn is_testnet(&self) -> bool {
+        match self {
+            L1Network::Localhost | L1Network::Sepolia | L1Network::Holesky => true,
+            L1Network::Mainnet => false,
+        }
+    }
+
+    /// Returns the production network a testnet stands in for. `None` for production networks
+    /// and for [`L1Network::Localhost`].
+    #[must_use]
+    pub fn production_counterpart(&self) -> Option<Self> {
+        match self {
+            L1Network::Sepolia | L1Network::Holesky => Some(L1Network::Mainnet),
+            L1Network::Localhost | L1Network::Mainnet => None,
+        }
+    }
+
+    /// Returns the network with the given L1 chain ID, if it's known.
+    #[must_use]
+    pub fn from_chain_id(chain_id: u64) -> Option<Self> {
+        Self::iter().find(|network| network.chain_id() == chain_id)
+    }
+
+    /// Same as [`Self::from_chain_id()`], but falls back to [`L1Network::Localhost`] for unknown chain IDs.
+    #[must_use]
+    pub fn from_chain_id_or_default(chain_id: u64) -> Self {
+        Self::from_chain_id(chain_id).unwrap_or_default()
+    }
+
+    /// Balance the deployer is expected to have before running deployment scripts on this network.
+    ///
+    /// All supported networks are ETH-denominated and share the same threshold.
+    #[must_use]
+    pub fn minimum_deployment_balance(&self) -> U256 {
+        WEI_IN_ETHER * 5
+    }
+
     pub fn avail_l1_da_validator_addr(&self) -> Option<Address> {
         match self {
             L1Network::Localhost => None,
@@ -49,3 +182,125 @@ impl L1Network {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_production_counterpart() {
+        for network in L1Network::iter() {
+            if let Some(counterpart) = network.production_counterpart() {
+                assert!(network.is_testnet(), "{network}");
+                assert!(!counterpart.is_testnet(), "{counterpart}");
+            }
+        }
+        assert_eq!(
+            L1Network::Sepolia.production_counterpart(),
+            Some(L1Network::Mainnet)
+        );
+        assert_eq!(L1Network::Localhost.production_counterpart(), None);
+        assert!(!L1Network::Mainnet.is_testnet());
+    }
+
+    #[test]
+    fn test_from_chain_id_roundtrip() {
+        for network in L1Network::iter() {
+            assert_eq!(L1Network::from_chain_id(network.chain_id()), Some(network));
+            assert_eq!(
+                L1Network::from_chain_id_or_default(network.chain_id()),
+                network
+            );
+        }
+    }
+
+    #[test]
+    fn test_from_str() {
+        for network in L1Network::iter() {
+            let name = network.to_possible_value().unwrap().get_name().to_owned();
+            assert_eq!(name.parse::<L1Network>(), Ok(network));
+            assert_eq!(name.to_uppercase().parse::<L1Network>(), Ok(network));
+            assert_eq!(network.to_string().parse::<L1Network>(), Ok(network));
+            assert_eq!(
+                network.chain_id().to_string().parse::<L1Network>(),
+                Ok(network)
+            );
+        }
+        assert_eq!(" Sepolia ".parse::<L1Network>(), Ok(L1Network::Sepolia));
+    }
+
+    #[test]
+    fn test_from_str_invalid() {
+        for input in ["", "sepolia-testnet", "eth", "56", "-1", "0x1"] {
+            let err = input.parse::<L1Network>().unwrap_err().to_string();
+            assert!(
+                err.contains("localhost, sepolia, holesky, mainnet"),
+                "{input}: {err}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_value_parser() {
+        let cmd = Command::new("zkstack").arg(
+            Arg::new("l1_network")
+                .long("l1-network")
+                .value_parser(L1NetworkValueParser),
+        );
+        let parse = |value: &str| {
+            cmd.clone()
+                .try_get_matches_from(["zkstack", "--l1-network", value])
+                .map(|matches| *matches.get_one::<L1Network>("l1_network").unwrap())
+        };
+        assert_eq!(parse("Sepolia").unwrap(), L1Network::Sepolia);
+        assert_eq!(parse("1").unwrap(), L1Network::Mainnet);
+        assert_eq!(parse("56").unwrap_err().kind(), ErrorKind::InvalidValue);
+
+        let possible_values: Vec<_> = L1NetworkValueParser
+            .possible_values()
+            .unwrap()
+            .map(|value| value.get_name().to_owned())
+            .collect();
+        assert_eq!(
+            possible_values,
+            ["localhost", "sepolia", "holesky", "mainnet"]
+        );
+    }
+
+    #[test]
+    fn test_block_time() {
+        assert_eq!(
+            L1Network::Localhost.block_time(),
+            Duration::from_millis(300)
+        );
+        assert_eq!(L1Network::Mainnet.block_time(), Duration::from_secs(12));
+        assert_eq!(
+            L1Network::Sepolia.block_time(),
+            L1Network::Mainnet.block_time()
+        );
+        assert_eq!(
+            L1Network::Holesky.block_time(),
+            L1Network::Mainnet.block_time()
+        );
+    }
+
+    #[test]
+    fn test_minimum_deployment_balance() {
+        for network in L1Network::iter() {
+            assert_eq!(
+                network.minimum_deployment_balance(),
+                U256::from(5_000_000_000_000_000_000u128),
+                "{network}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_from_chain_id_unknown() {
+        assert_eq!(L1Network::from_chain_id(56), None);
+        assert_eq!(
+            L1Network::from_chain_id_or_default(56),
+            L1Network::Localhost
+        );
+    }
+}
@@ -1,16 +1,12 @@
 _zkstack() {
     local i cur prev opts cmd
     COMPREPLY=()
-    if [[ "${BASH_VERSINFO[0]}" -ge 4 ]]; then
-        cur="$2"
-    else
-        cur="${COMP_WORDS[COMP_CWORD]}"
-    fi
-    prev="$3"
+    cur="${COMP_WORDS[COMP_CWORD]}"
+    prev="${COMP_WORDS[COMP_CWORD-1]}"
     cmd=""
     opts=""
 
-    for i in "${COMP_WORDS[@]:0:COMP_CWORD}"
+    for i in ${COMP_WORDS[@]}
     do
         case "${cmd},${i}" in
             ",$1")
@@ -31,6 +27,9 @@ _zkstack() {
             zkstack,contract-verifier)
                 cmd="zkstack__contract__verifier"
                 ;;
+            zkstack,ctm)
+                cmd="zkstack__ctm"
+                ;;
             zkstack,dev)
                 cmd="zkstack__dev"
                 ;;
@@ -67,6 +66,9 @@ _zkstack() {
             zkstack__chain,accept-chain-ownership)
                 cmd="zkstack__chain__accept__chain__ownership"
                 ;;
+            zkstack__chain,build-admin-batch)
+                cmd="zkstack__chain__build__admin__batch"
+                ;;
             zkstack__chain,build-transactions)
                 cmd="zkstack__chain__build__transactions"
                 ;;
@@ -124,15 +126,30 @@ _zkstack() {
             zkstack__chain,set-transaction-filterer-calldata)
                 cmd="zkstack__chain__set__transaction__filterer__calldata"
                 ;;
+            zkstack__chain,show-fee-config)
+                cmd="zkstack__chain__show__fee__config"
+                ;;
             zkstack__chain,update-token-multiplier-setter)
                 cmd="zkstack__chain__update__token__multiplier__setter"
                 ;;
+            zkstack__chain,upgrade-da-validator)
+                cmd="zkstack__chain__upgrade__da__validator"
+                ;;
+            zkstack__chain,wait-for-genesis)
+                cmd="zkstack__chain__wait__for__genesis"
+                ;;
             zkstack__chain__gateway,convert-to-gateway)
                 cmd="zkstack__chain__gateway__convert__to__gateway"
                 ;;
+            zkstack__chain__gateway,create-tx-filterer)
+                cmd="zkstack__chain__gateway__create__tx__filterer"
+                ;;
             zkstack__chain__gateway,finalize-chain-migration-from-gateway)
                 cmd="zkstack__chain__gateway__finalize__chain__migration__from__gateway"
                 ;;
+            zkstack__chain__gateway,finalize-chain-migration-to-gateway)
+                cmd="zkstack__chain__gateway__finalize__chain__migration__to__gateway"
+                ;;
             zkstack__chain__gateway,grant-gateway-transaction-filterer-whitelist-calldata)
                 cmd="zkstack__chain__gateway__grant__gateway__transaction__filterer__whitelist__calldata"
                 ;;
@@ -166,9 +183,15 @@ _zkstack() {
             zkstack__chain__gateway__help,convert-to-gateway)
                 cmd="zkstack__chain__gateway__help__convert__to__gateway"
                 ;;
+            zkstack__chain__gateway__help,create-tx-filterer)
+                cmd="zkstack__chain__gateway__help__create__tx__filterer"
+                ;;
             zkstack__chain__gateway__help,finalize-chain-migration-from-gateway)
                 cmd="zkstack__chain__gateway__help__finalize__chain__migration__from__gateway"
                 ;;
+            zkstack__chain__gateway__help,finalize-chain-migration-to-gateway)
+                cmd="zkstack__chain__gateway__help__finalize__chain__migration__to__gateway"
+                ;;
             zkstack__chain__gateway__help,grant-gateway-transaction-filterer-whitelist-calldata)
                 cmd="zkstack__chain__gateway__help__grant__gateway__transaction__filterer__whitelist__calldata"
                 ;;
@@ -220,6 +243,9 @@ _zkstack() {
             zkstack__chain__help,accept-chain-ownership)
                 cmd="zkstack__chain__help__accept__chain__ownership"
                 ;;
+            zkstack__chain__help,build-admin-batch)
+                cmd="zkstack__chain__help__build__admin__batch"
+                ;;
             zkstack__chain__help,build-transactions)
                 cmd="zkstack__chain__help__build__transactions"
                 ;;
@@ -277,15 +303,30 @@ _zkstack() {
             zkstack__chain__help,set-transaction-filterer-calldata)
                 cmd="zkstack__chain__help__set__transaction__filterer__calldata"
                 ;;
+            zkstack__chain__help,show-fee-config)
+                cmd="zkstack__chain__help__show__fee__config"
+                ;;
             zkstack__chain__help,update-token-multiplier-setter)
                 cmd="zkstack__chain__help__update__token__multiplier__setter"
                 ;;
+            zkstack__chain__help,upgrade-da-validator)
+                cmd="zkstack__chain__help__upgrade__da__validator"
+                ;;
+            zkstack__chain__help,wait-for-genesis)
+                cmd="zkstack__chain__help__wait__for__genesis"
+                ;;
             zkstack__chain__help__gateway,convert-to-gateway)
                 cmd="zkstack__chain__help__gateway__convert__to__gateway"
                 ;;
+            zkstack__chain__help__gateway,create-tx-filterer)
+                cmd="zkstack__chain__help__gateway__create__tx__filterer"
+                ;;
             zkstack__chain__help__gateway,finalize-chain-migration-from-gateway)
                 cmd="zkstack__chain__help__gateway__finalize__chain__migration__from__gateway"
                 ;;
+            zkstack__chain__help__gateway,finalize-chain-migration-to-gateway)
+                cmd="zkstack__chain__help__gateway__finalize__chain__migration__to__gateway"
+                ;;
             zkstack__chain__help__gateway,grant-gateway-transaction-filterer-whitelist-calldata)
                 cmd="zkstack__chain__help__gateway__grant__gateway__transaction__filterer__whitelist__calldata"
                 ;;
@@ -400,6 +441,24 @@ _zkstack() {
             zkstack__contract__verifier__help,wait)
                 cmd="zkstack__contract__verifier__help__wait"
                 ;;
+            zkstack__ctm,help)
+                cmd="zkstack__ctm__help"
+                ;;
+            zkstack__ctm,init-new-ctm)
+                cmd="zkstack__ctm__init__new__ctm"
+                ;;
+            zkstack__ctm,set-ctm-contracts)
+                cmd="zkstack__ctm__set__ctm__contracts"
+                ;;
+            zkstack__ctm__help,help)
+                cmd="zkstack__ctm__help__help"
+                ;;
+            zkstack__ctm__help,init-new-ctm)
+                cmd="zkstack__ctm__help__init__new__ctm"
+                ;;
+            zkstack__ctm__help,set-ctm-contracts)
+                cmd="zkstack__ctm__help__set__ctm__contracts"
+                ;;
             zkstack__dev,clean)
                 cmd="zkstack__dev__clean"
                 ;;
@@ -415,9 +474,21 @@ _zkstack() {
             zkstack__dev,fmt)
                 cmd="zkstack__dev__fmt"
                 ;;
+            zkstack__dev,generate-chain-upgrade)
+                cmd="zkstack__dev__generate__chain__upgrade"
+                ;;
+            zkstack__dev,generate-ecosystem-upgrade-calldata)
+                cmd="zkstack__dev__generate__ecosystem__upgrade__calldata"
+                ;;
             zkstack__dev,generate-genesis)
                 cmd="zkstack__dev__generate__genesis"
                 ;;
+            zkstack__dev,generate-v28-upgrade-calldata)
+                cmd="zkstack__dev__generate__v28__upgrade__calldata"
+                ;;
+            zkstack__dev,generate-v29-chain-upgrade)
+                cmd="zkstack__dev__generate__v29__chain__upgrade"
+                ;;
             zkstack__dev,help)
                 cmd="zkstack__dev__help"
                 ;;
@@ -433,6 +504,15 @@ _zkstack() {
             zkstack__dev,rich-account)
                 cmd="zkstack__dev__rich__account"
                 ;;
+            zkstack__dev,run-chain-upgrade)
+                cmd="zkstack__dev__run__chain__upgrade"
+                ;;
+            zkstack__dev,run-ecosystem-upgrade)
+                cmd="zkstack__dev__run__ecosystem__upgrade"
+                ;;
+            zkstack__dev,run-v29-chain-upgrade)
+                cmd="zkstack__dev__run__v29__chain__upgrade"
+                ;;
             zkstack__dev,send-transactions)
                 cmd="zkstack__dev__send__transactions"
                 ;;
@@ -448,6 +528,9 @@ _zkstack() {
             zkstack__dev,track-priority-ops)
                 cmd="zkstack__dev__track__priority__ops"
                 ;;
+            zkstack__dev,v27-evm-interpreter-upgrade-calldata)
+                cmd="zkstack__dev__v27__evm__interpreter__upgrade__calldata"
+                ;;
             zkstack__dev__clean,all)
                 cmd="zkstack__dev__clean__all"
                 ;;
@@ -535,9 +618,21 @@ _zkstack() {
             zkstack__dev__help,fmt)
                 cmd="zkstack__dev__help__fmt"
                 ;;
+            zkstack__dev__help,generate-chain-upgrade)
+                cmd="zkstack__dev__help__generate__chain__upgrade"
+                ;;
+            zkstack__dev__help,generate-ecosystem-upgrade-calldata)
+                cmd="zkstack__dev__help__generate__ecosystem__upgrade__calldata"
+                ;;
             zkstack__dev__help,generate-genesis)
                 cmd="zkstack__dev__help__generate__genesis"
                 ;;
+            zkstack__dev__help,generate-v28-upgrade-calldata)
+                cmd="zkstack__dev__help__generate__v28__upgrade__calldata"
+                ;;
+            zkstack__dev__help,generate-v29-chain-upgrade)
+                cmd="zkstack__dev__help__generate__v29__chain__upgrade"
+                ;;
             zkstack__dev__help,help)
                 cmd="zkstack__dev__help__help"
                 ;;
@@ -553,6 +648,15 @@ _zkstack() {
             zkstack__dev__help,rich-account)
                 cmd="zkstack__dev__help__rich__account"
                 ;;
+            zkstack__dev__help,run-chain-upgrade)
+                cmd="zkstack__dev__help__run__chain__upgrade"
+                ;;
+            zkstack__dev__help,run-ecosystem-upgrade)
+                cmd="zkstack__dev__help__run__ecosystem__upgrade"
+                ;;
+            zkstack__dev__help,run-v29-chain-upgrade)
+                cmd="zkstack__dev__help__run__v29__chain__upgrade"
+                ;;
             zkstack__dev__help,send-transactions)
                 cmd="zkstack__dev__help__send__transactions"
                 ;;
@@ -568,6 +672,9 @@ _zkstack() {
             zkstack__dev__help,track-priority-ops)
                 cmd="zkstack__dev__help__track__priority__ops"
                 ;;
+            zkstack__dev__help,v27-evm-interpreter-upgrade-calldata)
+                cmd="zkstack__dev__help__v27__evm__interpreter__upgrade__calldata"
+                ;;
             zkstack__dev__help__clean,all)
                 cmd="zkstack__dev__help__clean__all"
                 ;;
@@ -790,6 +897,12 @@ _zkstack() {
             zkstack__ecosystem,init)
                 cmd="zkstack__ecosystem__init"
                 ;;
+            zkstack__ecosystem,init-core-contracts)
+                cmd="zkstack__ecosystem__init__core__contracts"
+                ;;
+            zkstack__ecosystem,register-ctm)
+                cmd="zkstack__ecosystem__register__ctm"
+                ;;
             zkstack__ecosystem,setup-observability)
                 cmd="zkstack__ecosystem__setup__observability"
                 ;;
@@ -808,6 +921,12 @@ _zkstack() {
             zkstack__ecosystem__help,init)
                 cmd="zkstack__ecosystem__help__init"
                 ;;
+            zkstack__ecosystem__help,init-core-contracts)
+                cmd="zkstack__ecosystem__help__init__core__contracts"
+                ;;
+            zkstack__ecosystem__help,register-ctm)
+                cmd="zkstack__ecosystem__help__register__ctm"
+                ;;
             zkstack__ecosystem__help,setup-observability)
                 cmd="zkstack__ecosystem__help__setup__observability"
                 ;;
@@ -886,6 +1005,9 @@ _zkstack() {
             zkstack__help,contract-verifier)
                 cmd="zkstack__help__contract__verifier"
                 ;;
+            zkstack__help,ctm)
+                cmd="zkstack__help__ctm"
+                ;;
             zkstack__help,dev)
                 cmd="zkstack__help__dev"
                 ;;
@@ -922,6 +1044,9 @@ _zkstack() {
             zkstack__help__chain,accept-chain-ownership)
                 cmd="zkstack__help__chain__accept__chain__ownership"
                 ;;
+            zkstack__help__chain,build-admin-batch)
+                cmd="zkstack__help__chain__build__admin__batch"
+                ;;
             zkstack__help__chain,build-transactions)
                 cmd="zkstack__help__chain__build__transactions"
                 ;;
@@ -976,15 +1101,30 @@ _zkstack() {
             zkstack__help__chain,set-transaction-filterer-calldata)
                 cmd="zkstack__help__chain__set__transaction__filterer__calldata"
                 ;;
+            zkstack__help__chain,show-fee-config)
+                cmd="zkstack__help__chain__show__fee__config"
+                ;;
             zkstack__help__chain,update-token-multiplier-setter)
                 cmd="zkstack__help__chain__update__token__multiplier__setter"
                 ;;
+            zkstack__help__chain,upgrade-da-validator)
+                cmd="zkstack__help__chain__upgrade__da__validator"
+                ;;
+            zkstack__help__chain,wait-for-genesis)
+                cmd="zkstack__help__chain__wait__for__genesis"
+                ;;
             zkstack__help__chain__gateway,convert-to-gateway)
                 cmd="zkstack__help__chain__gateway__convert__to__gateway"
                 ;;
+            zkstack__help__chain__gateway,create-tx-filterer)
+                cmd="zkstack__help__chain__gateway__create__tx__filterer"
+                ;;
             zkstack__help__chain__gateway,finalize-chain-migration-from-gateway)
                 cmd="zkstack__help__chain__gateway__finalize__chain__migration__from__gateway"
                 ;;
+            zkstack__help__chain__gateway,finalize-chain-migration-to-gateway)
+                cmd="zkstack__help__chain__gateway__finalize__chain__migration__to__gateway"
+                ;;
             zkstack__help__chain__gateway,grant-gateway-transaction-filterer-whitelist-calldata)
                 cmd="zkstack__help__chain__gateway__grant__gateway__transaction__filterer__whitelist__calldata"
                 ;;
@@ -1048,6 +1188,12 @@ _zkstack() {
             zkstack__help__contract__verifier,wait)
                 cmd="zkstack__help__contract__verifier__wait"
                 ;;
+            zkstack__help__ctm,init-new-ctm)
+                cmd="zkstack__help__ctm__init__new__ctm"
+                ;;
+            zkstack__help__ctm,set-ctm-contracts)
+                cmd="zkstack__help__ctm__set__ctm__contracts"
+                ;;
             zkstack__help__dev,clean)
                 cmd="zkstack__help__dev__clean"
                 ;;
@@ -1063,9 +1209,21 @@ _zkstack() {
             zkstack__help__dev,fmt)
                 cmd="zkstack__help__dev__fmt"
                 ;;
+            zkstack__help__dev,generate-chain-upgrade)
+                cmd="zkstack__help__dev__generate__chain__upgrade"
+                ;;
+            zkstack__help__dev,generate-ecosystem-upgrade-calldata)
+                cmd="zkstack__help__dev__generate__ecosystem__upgrade__calldata"
+                ;;
             zkstack__help__dev,generate-genesis)
                 cmd="zkstack__help__dev__generate__genesis"
                 ;;
+            zkstack__help__dev,generate-v28-upgrade-calldata)
+                cmd="zkstack__help__dev__generate__v28__upgrade__calldata"
+                ;;
+            zkstack__help__dev,generate-v29-chain-upgrade)
+                cmd="zkstack__help__dev__generate__v29__chain__upgrade"
+                ;;
             zkstack__help__dev,init-test-wallet)
                 cmd="zkstack__help__dev__init__test__wallet"
                 ;;
@@ -1078,6 +1236,15 @@ _zkstack() {
             zkstack__help__dev,rich-account)
                 cmd="zkstack__help__dev__rich__account"
                 ;;
+            zkstack__help__dev,run-chain-upgrade)
+                cmd="zkstack__help__dev__run__chain__upgrade"
+                ;;
+            zkstack__help__dev,run-ecosystem-upgrade)
+                cmd="zkstack__help__dev__run__ecosystem__upgrade"
+                ;;
+            zkstack__help__dev,run-v29-chain-upgrade)
+                cmd="zkstack__help__dev__run__v29__chain__upgrade"
+                ;;
             zkstack__help__dev,send-transactions)
                 cmd="zkstack__help__dev__send__transactions"
                 ;;
@@ -1093,6 +1260,9 @@ _zkstack() {
             zkstack__help__dev,track-priority-ops)
                 cmd="zkstack__help__dev__track__priority__ops"
                 ;;
+            zkstack__help__dev,v27-evm-interpreter-upgrade-calldata)
+                cmd="zkstack__help__dev__v27__evm__interpreter__upgrade__calldata"
+                ;;
             zkstack__help__dev__clean,all)
                 cmd="zkstack__help__dev__clean__all"
                 ;;
@@ -1186,6 +1356,12 @@ _zkstack() {
             zkstack__help__ecosystem,init)
                 cmd="zkstack__help__ecosystem__init"
                 ;;
+            zkstack__help__ecosystem,init-core-contracts)
+                cmd="zkstack__help__ecosystem__init__core__contracts"
+                ;;
+            zkstack__help__ecosystem,register-ctm)
+                cmd="zkstack__help__ecosystem__register__ctm"
+                ;;
             zkstack__help__ecosystem,setup-observability)
                 cmd="zkstack__help__ecosystem__setup__observability"
                 ;;
@@ -1225,6 +1401,9 @@ _zkstack() {
             zkstack__help__prover,compressor-keys)
                 cmd="zkstack__help__prover__compressor__keys"
                 ;;
+            zkstack__help__prover,deploy-proving-network)
+                cmd="zkstack__help__prover__deploy__proving__network"
+                ;;
             zkstack__help__prover,init)
                 cmd="zkstack__help__prover__init"
                 ;;
@@ -1273,6 +1452,9 @@ _zkstack() {
             zkstack__prover,compressor-keys)
                 cmd="zkstack__prover__compressor__keys"
                 ;;
+            zkstack__prover,deploy-proving-network)
+                cmd="zkstack__prover__deploy__proving__network"
+                ;;
             zkstack__prover,help)
                 cmd="zkstack__prover__help"
                 ;;
@@ -1291,6 +1473,9 @@ _zkstack() {
             zkstack__prover__help,compressor-keys)
                 cmd="zkstack__prover__help__compressor__keys"
                 ;;
+            zkstack__prover__help,deploy-proving-network)
+                cmd="zkstack__prover__help__deploy__proving__network"
+                ;;
             zkstack__prover__help,help)
                 cmd="zkstack__prover__help__help"
                 ;;
@@ -1337,7 +1522,7 @@ _zkstack() {
 
     case "${cmd}" in
         zkstack)
-            opts="-v -h -V --verbose --chain --ignore-prerequisites --help --version autocomplete ecosystem chain dev prover server external-node containers contract-verifier portal private-rpc explorer consensus update markdown help"
+            opts="-v -h -V --verbose --chain --ignore-prerequisites --help --version autocomplete ecosystem ctm chain dev prover server external-node containers contract-verifier portal private-rpc explorer consensus update markdown help"
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 1 ]] ; then
                 COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
                 return 0
@@ -1385,7 +1570,7 @@ _zkstack() {
             return 0
             ;;
         zkstack__chain)
-            opts="-v -h --verbose --chain --ignore-prerequisites --help create build-transactions init genesis register-chain deploy-l2-contracts accept-chain-ownership deploy-consensus-registry deploy-multicall3 deploy-timestamp-asserter deploy-l2da-validator deploy-upgrader deploy-paymaster update-token-multiplier-setter set-transaction-filterer-calldata set-da-validator-pair-calldata enable-evm-emulator set-pubdata-pricing-mode set-da-validator-pair gateway help"
+            opts="-v -h --verbose --chain --ignore-prerequisites --help create build-transactions init genesis wait-for-genesis register-chain deploy-l2-contracts accept-chain-ownership deploy-consensus-registry deploy-multicall3 deploy-timestamp-asserter deploy-l2da-validator deploy-upgrader deploy-paymaster update-token-multiplier-setter set-transaction-filterer-calldata set-da-validator-pair-calldata build-admin-batch enable-evm-emulator set-pubdata-pricing-mode set-da-validator-pair upgrade-da-validator show-fee-config gateway help"
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 2 ]] ; then
                 COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
                 return 0
@@ -1403,7 +1588,7 @@ _zkstack() {
             return 0
             ;;
         zkstack__chain__accept__chain__ownership)
-            opts="-a -v -h --verify --verifier --verifier-url --verifier-api-key --resume --zksync --additional-args --verbose --chain --ignore-prerequisites --help"
+            opts="-a -v -h --verify --verifier --verifier-url --verifier-api-key --resume --zksync --min-balance --non-interactive --ignore-balance-check --additional-args --verbose --chain --ignore-prerequisites --help"
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 3 ]] ; then
                 COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
                 return 0
@@ -1425,6 +1610,10 @@ _zkstack() {
                     COMPREPLY=($(compgen -f "${cur}"))
                     return 0
                     ;;
+                --min-balance)
+                    COMPREPLY=($(compgen -f "${cur}"))
+                    return 0
+                    ;;
                 --additional-args)
                     COMPREPLY=($(compgen -f "${cur}"))
                     return 0
@@ -1444,8 +1633,42 @@ _zkstack() {
             COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
             return 0
             ;;
+        zkstack__chain__build__admin__batch)
+            opts="-v -h --transaction-filterer --l1-da-validator --l2-da-validator --pubdata-pricing-mode --verbose --chain --ignore-prerequisites --help <BRIDGEHUB_ADDRESS> <CHAIN_ID> <L1_RPC_URL>"
+            if [[ ${cur} == -* || ${COMP_CWORD} -eq 3 ]] ; then
+                COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+                return 0
+            fi
+            case "${prev}" in
+                --transaction-filterer)
+                    COMPREPLY=($(compgen -f "${cur}"))
+                    return 0
+                    ;;
+                --l1-da-validator)
+                    COMPREPLY=($(compgen -f "${cur}"))
+                    return 0
+                    ;;
+                --l2-da-validator)
+                    COMPREPLY=($(compgen -f "${cur}"))
+                    return 0
+                    ;;
+                --pubdata-pricing-mode)
+                    COMPREPLY=($(compgen -W "rollup validium" -- "${cur}"))
+                    return 0
+                    ;;
+                --chain)
+                    COMPREPLY=($(compgen -f "${cur}"))
+                    return 0
+                    ;;
+                *)
+                    COMPREPLY=()
+                    ;;
+            esac
+            COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+            return 0
+            ;;
         zkstack__chain__build__transactions)
-            opts="-o -a -v -h --out --verify --verifier --verifier-url --verifier-api-key --resume --zksync --additional-args --l1-rpc-url --verbose --chain --ignore-prerequisites --help"
+            opts="-o -a -v -h --out --verify --verifier --verifier-url --verifier-api-key --resume --zksync --min-balance --non-interactive --ignore-balance-check --additional-args --l1-rpc-url --output-format --verbose --chain --ignore-prerequisites --help"
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 3 ]] ; then
                 COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
                 return 0
@@ -1475,6 +1698,10 @@ _zkstack() {
                     COMPREPLY=($(compgen -f "${cur}"))
                     return 0
                     ;;
+                --min-balance)
+                    COMPREPLY=($(compgen -f "${cur}"))
+                    return 0
+                    ;;
                 --additional-args)
                     COMPREPLY=($(compgen -f "${cur}"))
                     return 0
@@ -1487,6 +1714,10 @@ _zkstack() {
                     COMPREPLY=($(compgen -f "${cur}"))
                     return 0
                     ;;
+                --output-format)
+                    COMPREPLY=($(compgen -W "forge safe-json" -- "${cur}"))
+                    return 0
+                    ;;
                 --chain)
                     COMPREPLY=($(compgen -f "${cur}"))
                     return 0
@@ -1499,7 +1730,7 @@ _zkstack() {
             return 0
             ;;
         zkstack__chain__create)
-            opts="-v -h --chain-name --chain-id --prover-mode --wallet-creation --wallet-path --l1-batch-commit-data-generator-mode --base-token-address --base-token-price-nominator --base-token-price-denominator --set-as-default --legacy-bridge --evm-emulator --update-submodules --tight-ports --verbose --chain --ignore-prerequisites --help"
+            opts="-v -h --chain-name --chain-id --prover-mode --wallet-creation --wallet-path --l1-batch-commit-data-generator-mode --base-token-address --base-token-price-nominator --base-token-price-denominator --set-as-default --legacy-bridge --evm-emulator --tight-ports --zksync-os --verbose --chain --ignore-prerequisites --help"
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 3 ]] ; then
                 COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
                 return 0
@@ -1560,10 +1791,6 @@ _zkstack() {
                     COMPREPLY=($(compgen -W "true false" -- "${cur}"))
                     return 0
                     ;;
-                --update-submodules)
-                    COMPREPLY=($(compgen -W "true false" -- "${cur}"))
-                    return 0
-                    ;;
                 --chain)
                     COMPREPLY=($(compgen -f "${cur}"))
                     return 0
@@ -1576,7 +1803,7 @@ _zkstack() {
             return 0
             ;;
         zkstack__chain__deploy__consensus__registry)
-            opts="-a -v -h --verify --verifier --verifier-url --verifier-api-key --resume --zksync --additional-args --verbose --chain --ignore-prerequisites --help"
+            opts="-a -v -h --verify --verifier --verifier-url --verifier-api-key --resume --zksync --min-balance --non-interactive --ignore-balance-check --additional-args --rotate-key --verbose --chain --ignore-prerequisites --help"
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 3 ]] ; then
                 COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
                 return 0
@@ -1598,6 +1825,10 @@ _zkstack() {
                     COMPREPLY=($(compgen -f "${cur}"))
                     return 0
                     ;;
+                --min-balance)
+                    COMPREPLY=($(compgen -f "${cur}"))
+                    return 0
+                    ;;
                 --additional-args)
                     COMPREPLY=($(compgen -f "${cur}"))
                     return 0
@@ -1618,7 +1849,7 @@ _zkstack() {
             return 0
             ;;
         zkstack__chain__deploy__l2__contracts)
-            opts="-a -v -h --verify --verifier --verifier-url --verifier-api-key --resume --zksync --additional-args --verbose --chain --ignore-prerequisites --help"
+            opts="-a -v -h --verify --verifier --verifier-url --verifier-api-key --resume --zksync --min-balance --non-interactive --ignore-balance-check --additional-args --verbose --chain --ignore-prerequisites --help"
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 3 ]] ; then
                 COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
                 return 0
@@ -1640,6 +1871,10 @@ _zkstack() {
                     COMPREPLY=($(compgen -f "${cur}"))
                     return 0
                     ;;
+                --min-balance)
+                    COMPREPLY=($(compgen -f "${cur}"))
+                    return 0
+                    ;;
                 --additional-args)
                     COMPREPLY=($(compgen -f "${cur}"))
                     return 0
@@ -1660,7 +1895,7 @@ _zkstack() {
             return 0
             ;;
         zkstack__chain__deploy__l2da__validator)
-            opts="-a -v -h --verify --verifier --verifier-url --verifier-api-key --resume --zksync --additional-args --verbose --chain --ignore-prerequisites --help"
+            opts="-a -v -h --verify --verifier --verifier-url --verifier-api-key --resume --zksync --min-balance --non-interactive --ignore-balance-check --additional-args --verbose --chain --ignore-prerequisites --help"
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 3 ]] ; then
                 COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
                 return 0
@@ -1682,6 +1917,10 @@ _zkstack() {
                     COMPREPLY=($(compgen -f "${cur}"))
                     return 0
                     ;;
+                --min-balance)
+                    COMPREPLY=($(compgen -f "${cur}"))
+                    return 0
+                    ;;
                 --additional-args)
                     COMPREPLY=($(compgen -f "${cur}"))
                     return 0
@@ -1702,7 +1941,7 @@ _zkstack() {
             return 0
             ;;
         zkstack__chain__deploy__multicall3)
-            opts="-a -v -h --verify --verifier --verifier-url --verifier-api-key --resume --zksync --additional-args --verbose --chain --ignore-prerequisites --help"
+            opts="-a -v -h --verify --verifier --verifier-url --verifier-api-key --resume --zksync --min-balance --non-interactive --ignore-balance-check --additional-args --verbose --chain --ignore-prerequisites --help"
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 3 ]] ; then
                 COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
                 return 0
@@ -1724,6 +1963,10 @@ _zkstack() {
                     COMPREPLY=($(compgen -f "${cur}"))
                     return 0
                     ;;
+                --min-balance)
+                    COMPREPLY=($(compgen -f "${cur}"))
+                    return 0
+                    ;;
                 --additional-args)
                     COMPREPLY=($(compgen -f "${cur}"))
                     return 0
@@ -1744,7 +1987,7 @@ _zkstack() {
             return 0
             ;;
         zkstack__chain__deploy__paymaster)
-            opts="-a -v -h --verify --verifier --verifier-url --verifier-api-key --resume --zksync --additional-args --verbose --chain --ignore-prerequisites --help"
+            opts="-a -v -h --verify --verifier --verifier-url --verifier-api-key --resume --zksync --min-balance --non-interactive --ignore-balance-check --additional-args --verbose --chain --ignore-prerequisites --help"
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 3 ]] ; then
                 COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
                 return 0
@@ -1766,6 +2009,10 @@ _zkstack() {
                     COMPREPLY=($(compgen -f "${cur}"))
                     return 0
                     ;;
+                --min-balance)
+                    COMPREPLY=($(compgen -f "${cur}"))
+                    return 0
+                    ;;
                 --additional-args)
                     COMPREPLY=($(compgen -f "${cur}"))
                     return 0
@@ -1786,7 +2033,7 @@ _zkstack() {
             return 0
             ;;
         zkstack__chain__deploy__timestamp__asserter)
-            opts="-a -v -h --verify --verifier --verifier-url --verifier-api-key --resume --zksync --additional-args --verbose --chain --ignore-prerequisites --help"
+            opts="-a -v -h --verify --verifier --verifier-url --verifier-api-key --resume --zksync --min-balance --non-interactive --ignore-balance-check --additional-args --verbose --chain --ignore-prerequisites --help"
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 3 ]] ; then
                 COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
                 return 0
@@ -1808,6 +2055,10 @@ _zkstack() {
                     COMPREPLY=($(compgen -f "${cur}"))
                     return 0
                     ;;
+                --min-balance)
+                    COMPREPLY=($(compgen -f "${cur}"))
+                    return 0
+                    ;;
                 --additional-args)
                     COMPREPLY=($(compgen -f "${cur}"))
                     return 0
@@ -1828,7 +2079,7 @@ _zkstack() {
             return 0
             ;;
         zkstack__chain__deploy__upgrader)
-            opts="-a -v -h --verify --verifier --verifier-url --verifier-api-key --resume --zksync --additional-args --verbose --chain --ignore-prerequisites --help"
+            opts="-a -v -h --verify --verifier --verifier-url --verifier-api-key --resume --zksync --min-balance --non-interactive --ignore-balance-check --additional-args --verbose --chain --ignore-prerequisites --help"
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 3 ]] ; then
                 COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
                 return 0
@@ -1850,6 +2101,10 @@ _zkstack() {
                     COMPREPLY=($(compgen -f "${cur}"))
                     return 0
                     ;;
+                --min-balance)
+                    COMPREPLY=($(compgen -f "${cur}"))
+                    return 0
+                    ;;
                 --additional-args)
                     COMPREPLY=($(compgen -f "${cur}"))
                     return 0
@@ -1870,7 +2125,7 @@ _zkstack() {
             return 0
             ;;
         zkstack__chain__enable__evm__emulator)
-            opts="-a -v -h --verify --verifier --verifier-url --verifier-api-key --resume --zksync --additional-args --verbose --chain --ignore-prerequisites --help"
+            opts="-a -v -h --verify --verifier --verifier-url --verifier-api-key --resume --zksync --min-balance --non-interactive --ignore-balance-check --additional-args --verbose --chain --ignore-prerequisites --help"
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 3 ]] ; then
                 COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
                 return 0
@@ -1892,6 +2147,10 @@ _zkstack() {
                     COMPREPLY=($(compgen -f "${cur}"))
                     return 0
                     ;;
+                --min-balance)
+                    COMPREPLY=($(compgen -f "${cur}"))
+                    return 0
+                    ;;
                 --additional-args)
                     COMPREPLY=($(compgen -f "${cur}"))
                     return 0
@@ -1912,7 +2171,7 @@ _zkstack() {
             return 0
             ;;
         zkstack__chain__gateway)
-            opts="-v -h --verbose --chain --ignore-prerequisites --help grant-gateway-transaction-filterer-whitelist-calldata notify-about-to-gateway-update-calldata notify-about-from-gateway-update-calldata migrate-to-gateway-calldata migrate-from-gateway-calldata finalize-chain-migration-from-gateway convert-to-gateway migrate-to-gateway migrate-from-gateway notify-about-to-gateway-update notify-about-from-gateway-update help"
+            opts="-v -h --verbose --chain --ignore-prerequisites --help grant-gateway-transaction-filterer-whitelist-calldata notify-about-to-gateway-update-calldata notify-about-from-gateway-update-calldata migrate-to-gateway-calldata migrate-from-gateway-calldata finalize-chain-migration-from-gateway create-tx-filterer convert-to-gateway migrate-to-gateway finalize-chain-migration-to-gateway migrate-from-gateway notify-about-to-gateway-update notify-about-from-gateway-update help"
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 3 ]] ; then
                 COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
                 return 0
@@ -1930,7 +2189,61 @@ _zkstack() {
             return 0
             ;;
         zkstack__chain__gateway__convert__to__gateway)
-            opts="-a -v -h --verify --verifier --verifier-url --verifier-api-key --resume --zksync --additional-args --verbose --chain --ignore-prerequisites --help"
+            opts="-a -v -h --verify --verifier --verifier-url --verifier-api-key --resume --zksync --min-balance --non-interactive --ignore-balance-check --additional-args --bridgehub-addr --ctm-chain-id --only-save-calldata --verbose --chain --ignore-prerequisites --help"
+            if [[ ${cur} == -* || ${COMP_CWORD} -eq 4 ]] ; then
+                COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+                return 0
+            fi
+            case "${prev}" in
+                --verify)
+                    COMPREPLY=($(compgen -W "true false" -- "${cur}"))
+                    return 0
+                    ;;
+                --verifier)
+                    COMPREPLY=($(compgen -W "etherscan sourcify blockscout oklink" -- "${cur}"))
+                    return 0
+                    ;;
+                --verifier-url)
+                    COMPREPLY=($(compgen -f "${cur}"))
+                    return 0
+                    ;;
+                --verifier-api-key)
+                    COMPREPLY=($(compgen -f "${cur}"))
+                    return 0
+                    ;;
+                --min-balance)
+                    COMPREPLY=($(compgen -f "${cur}"))
+                    return 0
+                    ;;
+                --additional-args)
+                    COMPREPLY=($(compgen -f "${cur}"))
+                    return 0
+                    ;;
+                -a)
+                    COMPREPLY=($(compgen -f "${cur}"))
+                    return 0
+                    ;;
+                --bridgehub-addr)
+                    COMPREPLY=($(compgen -f "${cur}"))
+                    return 0
+                    ;;
+                --ctm-chain-id)
+                    COMPREPLY=($(compgen -f "${cur}"))
+                    return 0
+                    ;;
+                --chain)
+                    COMPREPLY=($(compgen -f "${cur}"))
+                    return 0
+                    ;;
+                *)
+                    COMPREPLY=()
+                    ;;
+            esac
+            COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+            return 0
+            ;;
+        zkstack__chain__gateway__create__tx__filterer)
+            opts="-a -v -h --verify --verifier --verifier-url --verifier-api-key --resume --zksync --min-balance --non-interactive --ignore-balance-check --additional-args --verbose --chain --ignore-prerequisites --help"
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 4 ]] ; then
                 COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
                 return 0
@@ -1952,6 +2265,10 @@ _zkstack() {
                     COMPREPLY=($(compgen -f "${cur}"))
                     return 0
                     ;;
+                --min-balance)
+                    COMPREPLY=($(compgen -f "${cur}"))
+                    return 0
+                    ;;
                 --additional-args)
                     COMPREPLY=($(compgen -f "${cur}"))
                     return 0
@@ -2017,6 +2334,60 @@ _zkstack() {
             COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
             return 0
             ;;
+        zkstack__chain__gateway__finalize__chain__migration__to__gateway)
+            opts="-a -v -h --verify --verifier --verifier-url --verifier-api-key --resume --zksync --min-balance --non-interactive --ignore-balance-check --additional-args --gateway-chain-name --deploy-paymaster --verbose --chain --ignore-prerequisites --help"
+            if [[ ${cur} == -* || ${COMP_CWORD} -eq 4 ]] ; then
+                COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+                return 0
+            fi
+            case "${prev}" in
+                --verify)
+                    COMPREPLY=($(compgen -W "true false" -- "${cur}"))
+                    return 0
+                    ;;
+                --verifier)
+                    COMPREPLY=($(compgen -W "etherscan sourcify blockscout oklink" -- "${cur}"))
+                    return 0
+                    ;;
+                --verifier-url)
+                    COMPREPLY=($(compgen -f "${cur}"))
+                    return 0
+                    ;;
+                --verifier-api-key)
+                    COMPREPLY=($(compgen -f "${cur}"))
+                    return 0
+                    ;;
+                --min-balance)
+                    COMPREPLY=($(compgen -f "${cur}"))
+                    return 0
+                    ;;
+                --additional-args)
+                    COMPREPLY=($(compgen -f "${cur}"))
+                    return 0
+                    ;;
+                -a)
+                    COMPREPLY=($(compgen -f "${cur}"))
+                    return 0
+                    ;;
+                --gateway-chain-name)
+                    COMPREPLY=($(compgen -f "${cur}"))
+                    return 0
+                    ;;
+                --deploy-paymaster)
+                    COMPREPLY=($(compgen -W "true false" -- "${cur}"))
+                    return 0
+                    ;;
+                --chain)
+                    COMPREPLY=($(compgen -f "${cur}"))
+                    return 0
+                    ;;
+                *)
+                    COMPREPLY=()
+                    ;;
+            esac
+            COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+            return 0
+            ;;
         zkstack__chain__gateway__grant__gateway__transaction__filterer__whitelist__calldata)
             opts="-v -h --bridgehub-addr --gateway-chain-id --l1-rpc-url --grantees --verbose --chain --ignore-prerequisites --help"
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 4 ]] ; then
@@ -2052,7 +2423,7 @@ _zkstack() {
             return 0
             ;;
         zkstack__chain__gateway__help)
-            opts="grant-gateway-transaction-filterer-whitelist-calldata notify-about-to-gateway-update-calldata notify-about-from-gateway-update-calldata migrate-to-gateway-calldata migrate-from-gateway-calldata finalize-chain-migration-from-gateway convert-to-gateway migrate-to-gateway migrate-from-gateway notify-about-to-gateway-update notify-about-from-gateway-update help"
+            opts="grant-gateway-transaction-filterer-whitelist-calldata notify-about-to-gateway-update-calldata notify-about-from-gateway-update-calldata migrate-to-gateway-calldata migrate-from-gateway-calldata finalize-chain-migration-from-gateway create-tx-filterer convert-to-gateway migrate-to-gateway finalize-chain-migration-to-gateway migrate-from-gateway notify-about-to-gateway-update notify-about-from-gateway-update help"
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 4 ]] ; then
                 COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
                 return 0
@@ -2079,7 +2450,7 @@ _zkstack() {
             COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
             return 0
             ;;
-        zkstack__chain__gateway__help__finalize__chain__migration__from__gateway)
+        zkstack__chain__gateway__help__create__tx__filterer)
             opts=""
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 5 ]] ; then
                 COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
@@ -2093,7 +2464,7 @@ _zkstack() {
             COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
             return 0
             ;;
-        zkstack__chain__gateway__help__grant__gateway__transaction__filterer__whitelist__calldata)
+        zkstack__chain__gateway__help__finalize__chain__migration__from__gateway)
             opts=""
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 5 ]] ; then
                 COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
@@ -2107,7 +2478,7 @@ _zkstack() {
             COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
             return 0
             ;;
-        zkstack__chain__gateway__help__help)
+        zkstack__chain__gateway__help__finalize__chain__migration__to__gateway)
             opts=""
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 5 ]] ; then
                 COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
@@ -2121,7 +2492,7 @@ _zkstack() {
             COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
             return 0
             ;;
-        zkstack__chain__gateway__help__migrate__from__gateway)
+        zkstack__chain__gateway__help__grant__gateway__transaction__filterer__whitelist__calldata)
             opts=""
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 5 ]] ; then
                 COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
@@ -2135,7 +2506,7 @@ _zkstack() {
             COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
             return 0
             ;;
-        zkstack__chain__gateway__help__migrate__from__gateway__calldata)
+        zkstack__chain__gateway__help__help)
             opts=""
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 5 ]] ; then
                 COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
@@ -2149,7 +2520,7 @@ _zkstack() {
             COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
             return 0
             ;;
-        zkstack__chain__gateway__help__migrate__to__gateway)
+        zkstack__chain__gateway__help__migrate__from__gateway)
             opts=""
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 5 ]] ; then
                 COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
@@ -2163,7 +2534,7 @@ _zkstack() {
             COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
             return 0
             ;;
-        zkstack__chain__gateway__help__migrate__to__gateway__calldata)
+        zkstack__chain__gateway__help__migrate__from__gateway__calldata)
             opts=""
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 5 ]] ; then
                 COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
@@ -2177,7 +2548,7 @@ _zkstack() {
             COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
             return 0
             ;;
-        zkstack__chain__gateway__help__notify__about__from__gateway__update)
+        zkstack__chain__gateway__help__migrate__to__gateway)
             opts=""
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 5 ]] ; then
                 COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
@@ -2191,7 +2562,7 @@ _zkstack() {
             COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
             return 0
             ;;
-        zkstack__chain__gateway__help__notify__about__from__gateway__update__calldata)
+        zkstack__chain__gateway__help__migrate__to__gateway__calldata)
             opts=""
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 5 ]] ; then
                 COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
@@ -2205,7 +2576,7 @@ _zkstack() {
             COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
             return 0
             ;;
-        zkstack__chain__gateway__help__notify__about__to__gateway__update)
+        zkstack__chain__gateway__help__notify__about__from__gateway__update)
             opts=""
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 5 ]] ; then
                 COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
@@ -2219,7 +2590,7 @@ _zkstack() {
             COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
             return 0
             ;;
-        zkstack__chain__gateway__help__notify__about__to__gateway__update__calldata)
+        zkstack__chain__gateway__help__notify__about__from__gateway__update__calldata)
             opts=""
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 5 ]] ; then
                 COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
@@ -2233,9 +2604,37 @@ _zkstack() {
             COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
             return 0
             ;;
-        zkstack__chain__gateway__migrate__from__gateway)
-            opts="-a -v -h --verify --verifier --verifier-url --verifier-api-key --resume --zksync --additional-args --gateway-chain-name --verbose --chain --ignore-prerequisites --help"
-            if [[ ${cur} == -* || ${COMP_CWORD} -eq 4 ]] ; then
+        zkstack__chain__gateway__help__notify__about__to__gateway__update)
+            opts=""
+            if [[ ${cur} == -* || ${COMP_CWORD} -eq 5 ]] ; then
+                COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+                return 0
+            fi
+            case "${prev}" in
+                *)
+                    COMPREPLY=()
+                    ;;
+            esac
+            COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+            return 0
+            ;;
+        zkstack__chain__gateway__help__notify__about__to__gateway__update__calldata)
+            opts=""
+            if [[ ${cur} == -* || ${COMP_CWORD} -eq 5 ]] ; then
+                COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+                return 0
+            fi
+            case "${prev}" in
+                *)
+                    COMPREPLY=()
+                    ;;
+            esac
+            COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+            return 0
+            ;;
+        zkstack__chain__gateway__migrate__from__gateway)
+            opts="-a -v -h --verify --verifier --verifier-url --verifier-api-key --resume --zksync --min-balance --non-interactive --ignore-balance-check --additional-args --gateway-chain-name --verbose --chain --ignore-prerequisites --help"
+            if [[ ${cur} == -* || ${COMP_CWORD} -eq 4 ]] ; then
                 COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
                 return 0
             fi
@@ -2256,6 +2655,10 @@ _zkstack() {
                     COMPREPLY=($(compgen -f "${cur}"))
                     return 0
                     ;;
+                --min-balance)
+                    COMPREPLY=($(compgen -f "${cur}"))
+                    return 0
+                    ;;
                 --additional-args)
                     COMPREPLY=($(compgen -f "${cur}"))
                     return 0
@@ -2334,7 +2737,7 @@ _zkstack() {
             return 0
             ;;
         zkstack__chain__gateway__migrate__to__gateway)
-            opts="-a -v -h --verify --verifier --verifier-url --verifier-api-key --resume --zksync --additional-args --gateway-chain-name --verbose --chain --ignore-prerequisites --help"
+            opts="-a -v -h --verify --verifier --verifier-url --verifier-api-key --resume --zksync --min-balance --non-interactive --ignore-balance-check --additional-args --gateway-chain-name --verbose --chain --ignore-prerequisites --help"
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 4 ]] ; then
                 COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
                 return 0
@@ -2356,6 +2759,10 @@ _zkstack() {
                     COMPREPLY=($(compgen -f "${cur}"))
                     return 0
                     ;;
+                --min-balance)
+                    COMPREPLY=($(compgen -f "${cur}"))
+                    return 0
+                    ;;
                 --additional-args)
                     COMPREPLY=($(compgen -f "${cur}"))
                     return 0
@@ -2450,7 +2857,7 @@ _zkstack() {
             return 0
             ;;
         zkstack__chain__gateway__notify__about__from__gateway__update)
-            opts="-a -v -h --verify --verifier --verifier-url --verifier-api-key --resume --zksync --additional-args --verbose --chain --ignore-prerequisites --help"
+            opts="-a -v -h --verify --verifier --verifier-url --verifier-api-key --resume --zksync --min-balance --non-interactive --ignore-balance-check --additional-args --verbose --chain --ignore-prerequisites --help"
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 4 ]] ; then
                 COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
                 return 0
@@ -2472,6 +2879,10 @@ _zkstack() {
                     COMPREPLY=($(compgen -f "${cur}"))
                     return 0
                     ;;
+                --min-balance)
+                    COMPREPLY=($(compgen -f "${cur}"))
+                    return 0
+                    ;;
                 --additional-args)
                     COMPREPLY=($(compgen -f "${cur}"))
                     return 0
@@ -2518,7 +2929,7 @@ _zkstack() {
             return 0
             ;;
         zkstack__chain__gateway__notify__about__to__gateway__update)
-            opts="-a -v -h --verify --verifier --verifier-url --verifier-api-key --resume --zksync --additional-args --verbose --chain --ignore-prerequisites --help"
+            opts="-a -v -h --verify --verifier --verifier-url --verifier-api-key --resume --zksync --min-balance --non-interactive --ignore-balance-check --additional-args --verbose --chain --ignore-prerequisites --help"
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 4 ]] ; then
                 COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
                 return 0
@@ -2540,6 +2951,10 @@ _zkstack() {
                     COMPREPLY=($(compgen -f "${cur}"))
                     return 0
                     ;;
+                --min-balance)
+                    COMPREPLY=($(compgen -f "${cur}"))
+                    return 0
+                    ;;
                 --additional-args)
                     COMPREPLY=($(compgen -f "${cur}"))
                     return 0
@@ -2586,7 +3001,7 @@ _zkstack() {
             return 0
             ;;
         zkstack__chain__genesis)
-            opts="-d -d -v -h --server-db-url --server-db-name --dev --dont-drop --server-command --verbose --chain --ignore-prerequisites --help init-database server help"
+            opts="-d -d -v -h --server-db-url --server-db-name --dev --dont-drop --server-command --skip-l1-check --verbose --chain --ignore-prerequisites --help init-database server help"
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 3 ]] ; then
                 COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
                 return 0
@@ -2672,7 +3087,7 @@ _zkstack() {
             return 0
             ;;
         zkstack__chain__genesis__init__database)
-            opts="-d -d -v -h --server-db-url --server-db-name --dev --dont-drop --server-command --verbose --chain --ignore-prerequisites --help"
+            opts="-d -d -v -h --server-db-url --server-db-name --dev --dont-drop --server-command --skip-l1-check --verbose --chain --ignore-prerequisites --help"
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 4 ]] ; then
                 COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
                 return 0
@@ -2720,7 +3135,7 @@ _zkstack() {
             return 0
             ;;
         zkstack__chain__help)
-            opts="create build-transactions init genesis register-chain deploy-l2-contracts accept-chain-ownership deploy-consensus-registry deploy-multicall3 deploy-timestamp-asserter deploy-l2da-validator deploy-upgrader deploy-paymaster update-token-multiplier-setter set-transaction-filterer-calldata set-da-validator-pair-calldata enable-evm-emulator set-pubdata-pricing-mode set-da-validator-pair gateway help"
+            opts="create build-transactions init genesis wait-for-genesis register-chain deploy-l2-contracts accept-chain-ownership deploy-consensus-registry deploy-multicall3 deploy-timestamp-asserter deploy-l2da-validator deploy-upgrader deploy-paymaster update-token-multiplier-setter set-transaction-filterer-calldata set-da-validator-pair-calldata build-admin-batch enable-evm-emulator set-pubdata-pricing-mode set-da-validator-pair upgrade-da-validator show-fee-config gateway help"
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 3 ]] ; then
                 COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
                 return 0
@@ -2747,6 +3162,20 @@ _zkstack() {
             COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
             return 0
             ;;
+        zkstack__chain__help__build__admin__batch)
+            opts=""
+            if [[ ${cur} == -* || ${COMP_CWORD} -eq 4 ]] ; then
+                COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+                return 0
+            fi
+            case "${prev}" in
+                *)
+                    COMPREPLY=()
+                    ;;
+            esac
+            COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+            return 0
+            ;;
         zkstack__chain__help__build__transactions)
             opts=""
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 4 ]] ; then
@@ -2888,7 +3317,7 @@ _zkstack() {
             return 0
             ;;
         zkstack__chain__help__gateway)
-            opts="grant-gateway-transaction-filterer-whitelist-calldata notify-about-to-gateway-update-calldata notify-about-from-gateway-update-calldata migrate-to-gateway-calldata migrate-from-gateway-calldata finalize-chain-migration-from-gateway convert-to-gateway migrate-to-gateway migrate-from-gateway notify-about-to-gateway-update notify-about-from-gateway-update"
+            opts="grant-gateway-transaction-filterer-whitelist-calldata notify-about-to-gateway-update-calldata notify-about-from-gateway-update-calldata migrate-to-gateway-calldata migrate-from-gateway-calldata finalize-chain-migration-from-gateway create-tx-filterer convert-to-gateway migrate-to-gateway finalize-chain-migration-to-gateway migrate-from-gateway notify-about-to-gateway-update notify-about-from-gateway-update"
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 4 ]] ; then
                 COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
                 return 0
@@ -2915,6 +3344,20 @@ _zkstack() {
             COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
             return 0
             ;;
+        zkstack__chain__help__gateway__create__tx__filterer)
+            opts=""
+            if [[ ${cur} == -* || ${COMP_CWORD} -eq 5 ]] ; then
+                COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+                return 0
+            fi
+            case "${prev}" in
+                *)
+                    COMPREPLY=()
+                    ;;
+            esac
+            COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+            return 0
+            ;;
         zkstack__chain__help__gateway__finalize__chain__migration__from__gateway)
             opts=""
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 5 ]] ; then
@@ -2929,6 +3372,20 @@ _zkstack() {
             COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
             return 0
             ;;
+        zkstack__chain__help__gateway__finalize__chain__migration__to__gateway)
+            opts=""
+            if [[ ${cur} == -* || ${COMP_CWORD} -eq 5 ]] ; then
+                COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+                return 0
+            fi
+            case "${prev}" in
+                *)
+                    COMPREPLY=()
+                    ;;
+            esac
+            COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+            return 0
+            ;;
         zkstack__chain__help__gateway__grant__gateway__transaction__filterer__whitelist__calldata)
             opts=""
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 5 ]] ; then
@@ -3209,6 +3666,20 @@ _zkstack() {
             COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
             return 0
             ;;
+        zkstack__chain__help__show__fee__config)
+            opts=""
+            if [[ ${cur} == -* || ${COMP_CWORD} -eq 4 ]] ; then
+                COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+                return 0
+            fi
+            case "${prev}" in
+                *)
+                    COMPREPLY=()
+                    ;;
+            esac
+            COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+            return 0
+            ;;
         zkstack__chain__help__update__token__multiplier__setter)
             opts=""
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 4 ]] ; then
@@ -3223,8 +3694,36 @@ _zkstack() {
             COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
             return 0
             ;;
+        zkstack__chain__help__upgrade__da__validator)
+            opts=""
+            if [[ ${cur} == -* || ${COMP_CWORD} -eq 4 ]] ; then
+                COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+                return 0
+            fi
+            case "${prev}" in
+                *)
+                    COMPREPLY=()
+                    ;;
+            esac
+            COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+            return 0
+            ;;
+        zkstack__chain__help__wait__for__genesis)
+            opts=""
+            if [[ ${cur} == -* || ${COMP_CWORD} -eq 4 ]] ; then
+                COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+                return 0
+            fi
+            case "${prev}" in
+                *)
+                    COMPREPLY=()
+                    ;;
+            esac
+            COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+            return 0
+            ;;
         zkstack__chain__init)
-            opts="-a -d -v -h --verify --verifier --verifier-url --verifier-api-key --resume --zksync --additional-args --server-db-url --server-db-name --dont-drop --deploy-paymaster --l1-rpc-url --no-port-reallocation --update-submodules --make-permanent-rollup --dev --validium-type --server-command --verbose --chain --ignore-prerequisites --help configs help"
+            opts="-a -d -n -v -h --verify --verifier --verifier-url --verifier-api-key --resume --zksync --min-balance --non-interactive --ignore-balance-check --additional-args --server-db-url --server-db-name --dont-drop --deploy-paymaster --l1-rpc-url --no-port-reallocation --make-permanent-rollup --dev --validium-type --server-command --no-genesis --skip-priority-txs --skip-l1-check --max-block-age-secs --skip-network-check --verbose --chain --ignore-prerequisites --help configs help"
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 3 ]] ; then
                 COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
                 return 0
@@ -3246,6 +3745,10 @@ _zkstack() {
                     COMPREPLY=($(compgen -f "${cur}"))
                     return 0
                     ;;
+                --min-balance)
+                    COMPREPLY=($(compgen -f "${cur}"))
+                    return 0
+                    ;;
                 --additional-args)
                     COMPREPLY=($(compgen -f "${cur}"))
                     return 0
@@ -3270,14 +3773,6 @@ _zkstack() {
                     COMPREPLY=($(compgen -f "${cur}"))
                     return 0
                     ;;
-                --update-submodules)
-                    COMPREPLY=($(compgen -W "true false" -- "${cur}"))
-                    return 0
-                    ;;
-                --make-permanent-rollup)
-                    COMPREPLY=($(compgen -W "true false" -- "${cur}"))
-                    return 0
-                    ;;
                 --validium-type)
                     COMPREPLY=($(compgen -W "no-da avail eigen-da" -- "${cur}"))
                     return 0
@@ -3286,6 +3781,10 @@ _zkstack() {
                     COMPREPLY=($(compgen -f "${cur}"))
                     return 0
                     ;;
+                --max-block-age-secs)
+                    COMPREPLY=($(compgen -f "${cur}"))
+                    return 0
+                    ;;
                 --chain)
                     COMPREPLY=($(compgen -f "${cur}"))
                     return 0
@@ -3298,7 +3797,7 @@ _zkstack() {
             return 0
             ;;
         zkstack__chain__init__configs)
-            opts="-d -d -v -h --server-db-url --server-db-name --dev --dont-drop --server-command --l1-rpc-url --no-port-reallocation --verbose --chain --ignore-prerequisites --help"
+            opts="-d -d -v -h --server-db-url --server-db-name --dev --dont-drop --server-command --skip-l1-check --l1-rpc-url --no-port-reallocation --max-block-age-secs --skip-network-check --verbose --chain --ignore-prerequisites --help"
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 4 ]] ; then
                 COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
                 return 0
@@ -3320,6 +3819,10 @@ _zkstack() {
                     COMPREPLY=($(compgen -f "${cur}"))
                     return 0
                     ;;
+                --max-block-age-secs)
+                    COMPREPLY=($(compgen -f "${cur}"))
+                    return 0
+                    ;;
                 --chain)
                     COMPREPLY=($(compgen -f "${cur}"))
                     return 0
@@ -3374,7 +3877,7 @@ _zkstack() {
             return 0
             ;;
         zkstack__chain__register__chain)
-            opts="-a -v -h --verify --verifier --verifier-url --verifier-api-key --resume --zksync --additional-args --verbose --chain --ignore-prerequisites --help"
+            opts="-a -v -h --verify --verifier --verifier-url --verifier-api-key --resume --zksync --min-balance --non-interactive --ignore-balance-check --additional-args --skip-if-registered --verify-on-chain --verbose --chain --ignore-prerequisites --help"
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 3 ]] ; then
                 COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
                 return 0
@@ -3396,6 +3899,10 @@ _zkstack() {
                     COMPREPLY=($(compgen -f "${cur}"))
                     return 0
                     ;;
+                --min-balance)
+                    COMPREPLY=($(compgen -f "${cur}"))
+                    return 0
+                    ;;
                 --additional-args)
                     COMPREPLY=($(compgen -f "${cur}"))
                     return 0
@@ -3416,7 +3923,7 @@ _zkstack() {
             return 0
             ;;
         zkstack__chain__set__da__validator__pair)
-            opts="-a -v -h --verify --verifier --verifier-url --verifier-api-key --resume --zksync --additional-args --gateway --verbose --chain --ignore-prerequisites --help <L1_DA_VALIDATOR> [MAX_L1_GAS_PRICE]"
+            opts="-a -v -h --verify --verifier --verifier-url --verifier-api-key --resume --zksync --min-balance --non-interactive --ignore-balance-check --additional-args --gateway --no-forge --submit --verbose --chain --ignore-prerequisites --help <L1_DA_VALIDATOR> [MAX_L1_GAS_PRICE]"
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 3 ]] ; then
                 COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
                 return 0
@@ -3438,6 +3945,10 @@ _zkstack() {
                     COMPREPLY=($(compgen -f "${cur}"))
                     return 0
                     ;;
+                --min-balance)
+                    COMPREPLY=($(compgen -f "${cur}"))
+                    return 0
+                    ;;
                 --additional-args)
                     COMPREPLY=($(compgen -f "${cur}"))
                     return 0
@@ -3458,12 +3969,24 @@ _zkstack() {
             return 0
             ;;
         zkstack__chain__set__da__validator__pair__calldata)
-            opts="-v -h --verbose --chain --ignore-prerequisites --help <SL_DA_VALIDATOR> <L2_DA_VALIDATOR> <BRIDGEHUB_ADDRESS> <CHAIN_ID> <L1_RPC_URL> [EXPLICIT_SETTLEMENT_LAYER_CHAIN_ID] [MAX_L1_GAS_PRICE] [REFUND_RECIPIENT] [GW_RPC_URL]"
+            opts="-v -h --max-l1-gas-price --refund-recipient --gw-rpc-url --verbose --chain --ignore-prerequisites --help <SL_DA_VALIDATOR> <L2_DA_VALIDATOR> <BRIDGEHUB_ADDRESS> <CHAIN_ID> <L1_RPC_URL> [EXPLICIT_SETTLEMENT_LAYER_CHAIN_ID]"
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 3 ]] ; then
                 COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
                 return 0
             fi
             case "${prev}" in
+                --max-l1-gas-price)
+                    COMPREPLY=($(compgen -f "${cur}"))
+                    return 0
+                    ;;
+                --refund-recipient)
+                    COMPREPLY=($(compgen -f "${cur}"))
+                    return 0
+                    ;;
+                --gw-rpc-url)
+                    COMPREPLY=($(compgen -f "${cur}"))
+                    return 0
+                    ;;
                 --chain)
                     COMPREPLY=($(compgen -f "${cur}"))
                     return 0
@@ -3476,7 +3999,7 @@ _zkstack() {
             return 0
             ;;
         zkstack__chain__set__pubdata__pricing__mode)
-            opts="-r -a -v -h --rollup --verify --verifier --verifier-url --verifier-api-key --resume --zksync --additional-args --verbose --chain --ignore-prerequisites --help"
+            opts="-r -a -v -h --rollup --check-current --verify --verifier --verifier-url --verifier-api-key --resume --zksync --min-balance --non-interactive --ignore-balance-check --additional-args --verbose --chain --ignore-prerequisites --help"
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 3 ]] ; then
                 COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
                 return 0
@@ -3490,6 +4013,10 @@ _zkstack() {
                     COMPREPLY=($(compgen -W "true false" -- "${cur}"))
                     return 0
                     ;;
+                --check-current)
+                    COMPREPLY=($(compgen -W "true false" -- "${cur}"))
+                    return 0
+                    ;;
                 --verify)
                     COMPREPLY=($(compgen -W "true false" -- "${cur}"))
                     return 0
@@ -3506,6 +4033,10 @@ _zkstack() {
                     COMPREPLY=($(compgen -f "${cur}"))
                     return 0
                     ;;
+                --min-balance)
+                    COMPREPLY=($(compgen -f "${cur}"))
+                    return 0
+                    ;;
                 --additional-args)
                     COMPREPLY=($(compgen -f "${cur}"))
                     return 0
@@ -3526,12 +4057,38 @@ _zkstack() {
             return 0
             ;;
         zkstack__chain__set__transaction__filterer__calldata)
-            opts="-v -h --verbose --chain --ignore-prerequisites --help <TRANSACTION_FILTERER> <BRIDGEHUB_ADDRESS> <CHAIN_ID> <L1_RPC_URL>"
+            opts="-v -h --verify-abi --force --verbose --chain --ignore-prerequisites --help <TRANSACTION_FILTERER> <BRIDGEHUB_ADDRESS> <CHAIN_ID> <L1_RPC_URL>"
+            if [[ ${cur} == -* || ${COMP_CWORD} -eq 3 ]] ; then
+                COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+                return 0
+            fi
+            case "${prev}" in
+                --verify-abi)
+                    COMPREPLY=($(compgen -W "true false" -- "${cur}"))
+                    return 0
+                    ;;
+                --chain)
+                    COMPREPLY=($(compgen -f "${cur}"))
+                    return 0
+                    ;;
+                *)
+                    COMPREPLY=()
+                    ;;
+            esac
+            COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+            return 0
+            ;;
+        zkstack__chain__show__fee__config)
+            opts="-v -h --format --verbose --chain --ignore-prerequisites --help"
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 3 ]] ; then
                 COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
                 return 0
             fi
             case "${prev}" in
+                --format)
+                    COMPREPLY=($(compgen -W "text json" -- "${cur}"))
+                    return 0
+                    ;;
                 --chain)
                     COMPREPLY=($(compgen -f "${cur}"))
                     return 0
@@ -3544,7 +4101,53 @@ _zkstack() {
             return 0
             ;;
         zkstack__chain__update__token__multiplier__setter)
-            opts="-a -v -h --verify --verifier --verifier-url --verifier-api-key --resume --zksync --additional-args --verbose --chain --ignore-prerequisites --help"
+            opts="-a -v -h --verify --verifier --verifier-url --verifier-api-key --resume --zksync --min-balance --non-interactive --ignore-balance-check --additional-args --verbose --chain --ignore-prerequisites --help"
+            if [[ ${cur} == -* || ${COMP_CWORD} -eq 3 ]] ; then
+                COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+                return 0
+            fi
+            case "${prev}" in
+                --verify)
+                    COMPREPLY=($(compgen -W "true false" -- "${cur}"))
+                    return 0
+                    ;;
+                --verifier)
+                    COMPREPLY=($(compgen -W "etherscan sourcify blockscout oklink" -- "${cur}"))
+                    return 0
+                    ;;
+                --verifier-url)
+                    COMPREPLY=($(compgen -f "${cur}"))
+                    return 0
+                    ;;
+                --verifier-api-key)
+                    COMPREPLY=($(compgen -f "${cur}"))
+                    return 0
+                    ;;
+                --min-balance)
+                    COMPREPLY=($(compgen -f "${cur}"))
+                    return 0
+                    ;;
+                --additional-args)
+                    COMPREPLY=($(compgen -f "${cur}"))
+                    return 0
+                    ;;
+                -a)
+                    COMPREPLY=($(compgen -f "${cur}"))
+                    return 0
+                    ;;
+                --chain)
+                    COMPREPLY=($(compgen -f "${cur}"))
+                    return 0
+                    ;;
+                *)
+                    COMPREPLY=()
+                    ;;
+            esac
+            COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+            return 0
+            ;;
+        zkstack__chain__upgrade__da__validator)
+            opts="-a -v -h --verify --verifier --verifier-url --verifier-api-key --resume --zksync --min-balance --non-interactive --ignore-balance-check --additional-args --l1-da-validator --verbose --chain --ignore-prerequisites --help"
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 3 ]] ; then
                 COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
                 return 0
@@ -3566,6 +4169,10 @@ _zkstack() {
                     COMPREPLY=($(compgen -f "${cur}"))
                     return 0
                     ;;
+                --min-balance)
+                    COMPREPLY=($(compgen -f "${cur}"))
+                    return 0
+                    ;;
                 --additional-args)
                     COMPREPLY=($(compgen -f "${cur}"))
                     return 0
@@ -3574,6 +4181,40 @@ _zkstack() {
                     COMPREPLY=($(compgen -f "${cur}"))
                     return 0
                     ;;
+                --l1-da-validator)
+                    COMPREPLY=($(compgen -f "${cur}"))
+                    return 0
+                    ;;
+                --chain)
+                    COMPREPLY=($(compgen -f "${cur}"))
+                    return 0
+                    ;;
+                *)
+                    COMPREPLY=()
+                    ;;
+            esac
+            COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+            return 0
+            ;;
+        zkstack__chain__wait__for__genesis)
+            opts="-t -v -h --silent --timeout --poll-interval --verbose --chain --ignore-prerequisites --help"
+            if [[ ${cur} == -* || ${COMP_CWORD} -eq 3 ]] ; then
+                COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+                return 0
+            fi
+            case "${prev}" in
+                --timeout)
+                    COMPREPLY=($(compgen -f "${cur}"))
+                    return 0
+                    ;;
+                -t)
+                    COMPREPLY=($(compgen -f "${cur}"))
+                    return 0
+                    ;;
+                --poll-interval)
+                    COMPREPLY=($(compgen -f "${cur}"))
+                    return 0
+                    ;;
                 --chain)
                     COMPREPLY=($(compgen -f "${cur}"))
                     return 0
@@ -4043,8 +4684,178 @@ _zkstack() {
             COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
             return 0
             ;;
+        zkstack__ctm)
+            opts="-v -h --verbose --chain --ignore-prerequisites --help init-new-ctm set-ctm-contracts help"
+            if [[ ${cur} == -* || ${COMP_CWORD} -eq 2 ]] ; then
+                COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+                return 0
+            fi
+            case "${prev}" in
+                --chain)
+                    COMPREPLY=($(compgen -f "${cur}"))
+                    return 0
+                    ;;
+                *)
+                    COMPREPLY=()
+                    ;;
+            esac
+            COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+            return 0
+            ;;
+        zkstack__ctm__help)
+            opts="init-new-ctm set-ctm-contracts help"
+            if [[ ${cur} == -* || ${COMP_CWORD} -eq 3 ]] ; then
+                COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+                return 0
+            fi
+            case "${prev}" in
+                *)
+                    COMPREPLY=()
+                    ;;
+            esac
+            COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+            return 0
+            ;;
+        zkstack__ctm__help__help)
+            opts=""
+            if [[ ${cur} == -* || ${COMP_CWORD} -eq 4 ]] ; then
+                COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+                return 0
+            fi
+            case "${prev}" in
+                *)
+                    COMPREPLY=()
+                    ;;
+            esac
+            COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+            return 0
+            ;;
+        zkstack__ctm__help__init__new__ctm)
+            opts=""
+            if [[ ${cur} == -* || ${COMP_CWORD} -eq 4 ]] ; then
+                COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+                return 0
+            fi
+            case "${prev}" in
+                *)
+                    COMPREPLY=()
+                    ;;
+            esac
+            COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+            return 0
+            ;;
+        zkstack__ctm__help__set__ctm__contracts)
+            opts=""
+            if [[ ${cur} == -* || ${COMP_CWORD} -eq 4 ]] ; then
+                COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+                return 0
+            fi
+            case "${prev}" in
+                *)
+                    COMPREPLY=()
+                    ;;
+            esac
+            COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+            return 0
+            ;;
+        zkstack__ctm__init__new__ctm)
+            opts="-a -v -h --zksync-os --update-submodules --skip-contract-compilation-override --l1-rpc-url --support-l2-legacy-shared-bridge-test --bridgehub --reuse-gov-and-admin --contracts-src-path --default-configs-src-path --verify --verifier --verifier-url --verifier-api-key --resume --zksync --min-balance --non-interactive --ignore-balance-check --additional-args --verbose --chain --ignore-prerequisites --help"
+            if [[ ${cur} == -* || ${COMP_CWORD} -eq 3 ]] ; then
+                COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+                return 0
+            fi
+            case "${prev}" in
+                --update-submodules)
+                    COMPREPLY=($(compgen -W "true false" -- "${cur}"))
+                    return 0
+                    ;;
+                --l1-rpc-url)
+                    COMPREPLY=($(compgen -f "${cur}"))
+                    return 0
+                    ;;
+                --support-l2-legacy-shared-bridge-test)
+                    COMPREPLY=($(compgen -W "true false" -- "${cur}"))
+                    return 0
+                    ;;
+                --bridgehub)
+                    COMPREPLY=($(compgen -f "${cur}"))
+                    return 0
+                    ;;
+                --contracts-src-path)
+                    COMPREPLY=($(compgen -f "${cur}"))
+                    return 0
+                    ;;
+                --default-configs-src-path)
+                    COMPREPLY=($(compgen -f "${cur}"))
+                    return 0
+                    ;;
+                --verify)
+                    COMPREPLY=($(compgen -W "true false" -- "${cur}"))
+                    return 0
+                    ;;
+                --verifier)
+                    COMPREPLY=($(compgen -W "etherscan sourcify blockscout oklink" -- "${cur}"))
+                    return 0
+                    ;;
+                --verifier-url)
+                    COMPREPLY=($(compgen -f "${cur}"))
+                    return 0
+                    ;;
+                --verifier-api-key)
+                    COMPREPLY=($(compgen -f "${cur}"))
+                    return 0
+                    ;;
+                --min-balance)
+                    COMPREPLY=($(compgen -f "${cur}"))
+                    return 0
+                    ;;
+                --additional-args)
+                    COMPREPLY=($(compgen -f "${cur}"))
+                    return 0
+                    ;;
+                -a)
+                    COMPREPLY=($(compgen -f "${cur}"))
+                    return 0
+                    ;;
+                --chain)
+                    COMPREPLY=($(compgen -f "${cur}"))
+                    return 0
+                    ;;
+                *)
+                    COMPREPLY=()
+                    ;;
+            esac
+            COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+            return 0
+            ;;
+        zkstack__ctm__set__ctm__contracts)
+            opts="-v -h --contracts-src-path --default-configs-src-path --zksync-os --verbose --chain --ignore-prerequisites --help"
+            if [[ ${cur} == -* || ${COMP_CWORD} -eq 3 ]] ; then
+                COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+                return 0
+            fi
+            case "${prev}" in
+                --contracts-src-path)
+                    COMPREPLY=($(compgen -f "${cur}"))
+                    return 0
+                    ;;
+                --default-configs-src-path)
+                    COMPREPLY=($(compgen -f "${cur}"))
+                    return 0
+                    ;;
+                --chain)
+                    COMPREPLY=($(compgen -f "${cur}"))
+                    return 0
+                    ;;
+                *)
+                    COMPREPLY=()
+                    ;;
+            esac
+            COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+            return 0
+            ;;
         zkstack__dev)
-            opts="-v -h --verbose --chain --ignore-prerequisites --help database test clean snapshot lint fmt prover contracts config-writer send-transactions status generate-genesis init-test-wallet rich-account track-priority-ops help"
+            opts="-v -h --verbose --chain --ignore-prerequisites --help database test clean snapshot lint fmt prover contracts config-writer send-transactions status generate-genesis init-test-wallet rich-account track-priority-ops v27-evm-interpreter-upgrade-calldata generate-v28-upgrade-calldata generate-ecosystem-upgrade-calldata run-ecosystem-upgrade generate-chain-upgrade run-chain-upgrade run-v29-chain-upgrade generate-v29-chain-upgrade help"
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 2 ]] ; then
                 COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
                 return 0
@@ -4685,13 +5496,161 @@ _zkstack() {
             COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
             return 0
             ;;
-        zkstack__dev__fmt)
-            opts="-c -v -h --check --verbose --chain --ignore-prerequisites --help rustfmt prettier prettier-contracts"
+        zkstack__dev__fmt)
+            opts="-c -v -h --check --verbose --chain --ignore-prerequisites --help rustfmt prettier prettier-contracts"
+            if [[ ${cur} == -* || ${COMP_CWORD} -eq 3 ]] ; then
+                COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+                return 0
+            fi
+            case "${prev}" in
+                --chain)
+                    COMPREPLY=($(compgen -f "${cur}"))
+                    return 0
+                    ;;
+                *)
+                    COMPREPLY=()
+                    ;;
+            esac
+            COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+            return 0
+            ;;
+        zkstack__dev__generate__chain__upgrade)
+            opts="-v -h --dangerous-no-cross-check --dangerous-local-default-overrides --force-display-finalization-params --refund-recipient --upgrade-version --verbose --chain --ignore-prerequisites --help [UPGRADE_DESCRIPTION_PATH] [CHAIN_ID] [GW_CHAIN_ID] [L1_GAS_PRICE] [L1_RPC_URL] [L2_RPC_URL] [GW_RPC_URL] [SERVER_UPGRADE_TIMESTAMP]"
+            if [[ ${cur} == -* || ${COMP_CWORD} -eq 3 ]] ; then
+                COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+                return 0
+            fi
+            case "${prev}" in
+                --dangerous-no-cross-check)
+                    COMPREPLY=($(compgen -W "true false" -- "${cur}"))
+                    return 0
+                    ;;
+                --dangerous-local-default-overrides)
+                    COMPREPLY=($(compgen -W "true false" -- "${cur}"))
+                    return 0
+                    ;;
+                --force-display-finalization-params)
+                    COMPREPLY=($(compgen -W "true false" -- "${cur}"))
+                    return 0
+                    ;;
+                --refund-recipient)
+                    COMPREPLY=($(compgen -f "${cur}"))
+                    return 0
+                    ;;
+                --upgrade-version)
+                    COMPREPLY=($(compgen -W "v29-interop-a-ff v28-1vk v28-1vk-era" -- "${cur}"))
+                    return 0
+                    ;;
+                --chain)
+                    COMPREPLY=($(compgen -f "${cur}"))
+                    return 0
+                    ;;
+                *)
+                    COMPREPLY=()
+                    ;;
+            esac
+            COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+            return 0
+            ;;
+        zkstack__dev__generate__ecosystem__upgrade__calldata)
+            opts="-a -v -h --zksync-os --update-submodules --skip-contract-compilation-override --l1-rpc-url --verify --verifier --verifier-url --verifier-api-key --resume --zksync --min-balance --non-interactive --ignore-balance-check --additional-args --upgrade-version --ecosystem-upgrade-stage --server-command --verbose --chain --ignore-prerequisites --help"
+            if [[ ${cur} == -* || ${COMP_CWORD} -eq 3 ]] ; then
+                COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+                return 0
+            fi
+            case "${prev}" in
+                --update-submodules)
+                    COMPREPLY=($(compgen -W "true false" -- "${cur}"))
+                    return 0
+                    ;;
+                --l1-rpc-url)
+                    COMPREPLY=($(compgen -f "${cur}"))
+                    return 0
+                    ;;
+                --verify)
+                    COMPREPLY=($(compgen -W "true false" -- "${cur}"))
+                    return 0
+                    ;;
+                --verifier)
+                    COMPREPLY=($(compgen -W "etherscan sourcify blockscout oklink" -- "${cur}"))
+                    return 0
+                    ;;
+                --verifier-url)
+                    COMPREPLY=($(compgen -f "${cur}"))
+                    return 0
+                    ;;
+                --verifier-api-key)
+                    COMPREPLY=($(compgen -f "${cur}"))
+                    return 0
+                    ;;
+                --min-balance)
+                    COMPREPLY=($(compgen -f "${cur}"))
+                    return 0
+                    ;;
+                --additional-args)
+                    COMPREPLY=($(compgen -f "${cur}"))
+                    return 0
+                    ;;
+                -a)
+                    COMPREPLY=($(compgen -f "${cur}"))
+                    return 0
+                    ;;
+                --upgrade-version)
+                    COMPREPLY=($(compgen -W "v29-interop-a-ff v28-1vk v28-1vk-era" -- "${cur}"))
+                    return 0
+                    ;;
+                --ecosystem-upgrade-stage)
+                    COMPREPLY=($(compgen -W "no-governance-prepare ecosystem-admin governance-stage0 governance-stage1 governance-stage2 no-governance-stage2" -- "${cur}"))
+                    return 0
+                    ;;
+                --server-command)
+                    COMPREPLY=($(compgen -f "${cur}"))
+                    return 0
+                    ;;
+                --chain)
+                    COMPREPLY=($(compgen -f "${cur}"))
+                    return 0
+                    ;;
+                *)
+                    COMPREPLY=()
+                    ;;
+            esac
+            COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+            return 0
+            ;;
+        zkstack__dev__generate__genesis)
+            opts="-v -h --verbose --chain --ignore-prerequisites --help"
+            if [[ ${cur} == -* || ${COMP_CWORD} -eq 3 ]] ; then
+                COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+                return 0
+            fi
+            case "${prev}" in
+                --chain)
+                    COMPREPLY=($(compgen -f "${cur}"))
+                    return 0
+                    ;;
+                *)
+                    COMPREPLY=()
+                    ;;
+            esac
+            COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+            return 0
+            ;;
+        zkstack__dev__generate__v28__upgrade__calldata)
+            opts="-v -h --dangerous-no-cross-check --force-display-finalization-params --verbose --chain --ignore-prerequisites --help <UPGRADE_DESCRIPTION_PATH> <CHAIN_ID> <GW_CHAIN_ID> <L1_GAS_PRICE> <L1_RPC_URL> <L2_RPC_URL> <GW_RPC_URL> <SERVER_UPGRADE_TIMESTAMP>"
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 3 ]] ; then
                 COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
                 return 0
             fi
             case "${prev}" in
+                --dangerous-no-cross-check)
+                    COMPREPLY=($(compgen -W "true false" -- "${cur}"))
+                    return 0
+                    ;;
+                --force-display-finalization-params)
+                    COMPREPLY=($(compgen -W "true false" -- "${cur}"))
+                    return 0
+                    ;;
                 --chain)
                     COMPREPLY=($(compgen -f "${cur}"))
                     return 0
@@ -4703,13 +5662,37 @@ _zkstack() {
             COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
             return 0
             ;;
-        zkstack__dev__generate__genesis)
-            opts="-v -h --verbose --chain --ignore-prerequisites --help"
+        zkstack__dev__generate__v29__chain__upgrade)
+            opts="-v -h --dangerous-no-cross-check --dangerous-local-default-overrides --force-display-finalization-params --refund-recipient --operator --blob-operator --verbose --chain --ignore-prerequisites --help [UPGRADE_DESCRIPTION_PATH] [CHAIN_ID] [GW_CHAIN_ID] [L1_GAS_PRICE] [L1_RPC_URL] [L2_RPC_URL] [GW_RPC_URL] [SERVER_UPGRADE_TIMESTAMP]"
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 3 ]] ; then
                 COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
                 return 0
             fi
             case "${prev}" in
+                --dangerous-no-cross-check)
+                    COMPREPLY=($(compgen -W "true false" -- "${cur}"))
+                    return 0
+                    ;;
+                --dangerous-local-default-overrides)
+                    COMPREPLY=($(compgen -W "true false" -- "${cur}"))
+                    return 0
+                    ;;
+                --force-display-finalization-params)
+                    COMPREPLY=($(compgen -W "true false" -- "${cur}"))
+                    return 0
+                    ;;
+                --refund-recipient)
+                    COMPREPLY=($(compgen -f "${cur}"))
+                    return 0
+                    ;;
+                --operator)
+                    COMPREPLY=($(compgen -f "${cur}"))
+                    return 0
+                    ;;
+                --blob-operator)
+                    COMPREPLY=($(compgen -f "${cur}"))
+                    return 0
+                    ;;
                 --chain)
                     COMPREPLY=($(compgen -f "${cur}"))
                     return 0
@@ -4722,7 +5705,7 @@ _zkstack() {
             return 0
             ;;
         zkstack__dev__help)
-            opts="database test clean snapshot lint fmt prover contracts config-writer send-transactions status generate-genesis init-test-wallet rich-account track-priority-ops help"
+            opts="database test clean snapshot lint fmt prover contracts config-writer send-transactions status generate-genesis init-test-wallet rich-account track-priority-ops v27-evm-interpreter-upgrade-calldata generate-v28-upgrade-calldata generate-ecosystem-upgrade-calldata run-ecosystem-upgrade generate-chain-upgrade run-chain-upgrade run-v29-chain-upgrade generate-v29-chain-upgrade help"
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 3 ]] ; then
                 COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
                 return 0
@@ -4945,6 +5928,34 @@ _zkstack() {
             COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
             return 0
             ;;
+        zkstack__dev__help__generate__chain__upgrade)
+            opts=""
+            if [[ ${cur} == -* || ${COMP_CWORD} -eq 4 ]] ; then
+                COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+                return 0
+            fi
+            case "${prev}" in
+                *)
+                    COMPREPLY=()
+                    ;;
+            esac
+            COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+            return 0
+            ;;
+        zkstack__dev__help__generate__ecosystem__upgrade__calldata)
+            opts=""
+            if [[ ${cur} == -* || ${COMP_CWORD} -eq 4 ]] ; then
+                COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+                return 0
+            fi
+            case "${prev}" in
+                *)
+                    COMPREPLY=()
+                    ;;
+            esac
+            COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+            return 0
+            ;;
         zkstack__dev__help__generate__genesis)
             opts=""
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 4 ]] ; then
@@ -4959,6 +5970,34 @@ _zkstack() {
             COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
             return 0
             ;;
+        zkstack__dev__help__generate__v28__upgrade__calldata)
+            opts=""
+            if [[ ${cur} == -* || ${COMP_CWORD} -eq 4 ]] ; then
+                COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+                return 0
+            fi
+            case "${prev}" in
+                *)
+                    COMPREPLY=()
+                    ;;
+            esac
+            COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+            return 0
+            ;;
+        zkstack__dev__help__generate__v29__chain__upgrade)
+            opts=""
+            if [[ ${cur} == -* || ${COMP_CWORD} -eq 4 ]] ; then
+                COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+                return 0
+            fi
+            case "${prev}" in
+                *)
+                    COMPREPLY=()
+                    ;;
+            esac
+            COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+            return 0
+            ;;
         zkstack__dev__help__help)
             opts=""
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 4 ]] ; then
@@ -5071,6 +6110,48 @@ _zkstack() {
             COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
             return 0
             ;;
+        zkstack__dev__help__run__chain__upgrade)
+            opts=""
+            if [[ ${cur} == -* || ${COMP_CWORD} -eq 4 ]] ; then
+                COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+                return 0
+            fi
+            case "${prev}" in
+                *)
+                    COMPREPLY=()
+                    ;;
+            esac
+            COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+            return 0
+            ;;
+        zkstack__dev__help__run__ecosystem__upgrade)
+            opts=""
+            if [[ ${cur} == -* || ${COMP_CWORD} -eq 4 ]] ; then
+                COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+                return 0
+            fi
+            case "${prev}" in
+                *)
+                    COMPREPLY=()
+                    ;;
+            esac
+            COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+            return 0
+            ;;
+        zkstack__dev__help__run__v29__chain__upgrade)
+            opts=""
+            if [[ ${cur} == -* || ${COMP_CWORD} -eq 4 ]] ; then
+                COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+                return 0
+            fi
+            case "${prev}" in
+                *)
+                    COMPREPLY=()
+                    ;;
+            esac
+            COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+            return 0
+            ;;
         zkstack__dev__help__send__transactions)
             opts=""
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 4 ]] ; then
@@ -5337,6 +6418,20 @@ _zkstack() {
             COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
             return 0
             ;;
+        zkstack__dev__help__v27__evm__interpreter__upgrade__calldata)
+            opts=""
+            if [[ ${cur} == -* || ${COMP_CWORD} -eq 4 ]] ; then
+                COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+                return 0
+            fi
+            case "${prev}" in
+                *)
+                    COMPREPLY=()
+                    ;;
+            esac
+            COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+            return 0
+            ;;
         zkstack__dev__init__test__wallet)
             opts="-v -h --verbose --chain --ignore-prerequisites --help"
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 3 ]] ; then
@@ -5498,37 +6593,171 @@ _zkstack() {
                     COMPREPLY=($(compgen -f "${cur}"))
                     return 0
                     ;;
-                --version)
+                --version)
+                    COMPREPLY=($(compgen -f "${cur}"))
+                    return 0
+                    ;;
+                --chain)
+                    COMPREPLY=($(compgen -f "${cur}"))
+                    return 0
+                    ;;
+                *)
+                    COMPREPLY=()
+                    ;;
+            esac
+            COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+            return 0
+            ;;
+        zkstack__dev__prover__insert__version)
+            opts="-v -h --default --version --snark-wrapper --fflonk-snark-wrapper --verbose --chain --ignore-prerequisites --help"
+            if [[ ${cur} == -* || ${COMP_CWORD} -eq 4 ]] ; then
+                COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+                return 0
+            fi
+            case "${prev}" in
+                --version)
+                    COMPREPLY=($(compgen -f "${cur}"))
+                    return 0
+                    ;;
+                --snark-wrapper)
+                    COMPREPLY=($(compgen -f "${cur}"))
+                    return 0
+                    ;;
+                --fflonk-snark-wrapper)
+                    COMPREPLY=($(compgen -f "${cur}"))
+                    return 0
+                    ;;
+                --chain)
+                    COMPREPLY=($(compgen -f "${cur}"))
+                    return 0
+                    ;;
+                *)
+                    COMPREPLY=()
+                    ;;
+            esac
+            COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+            return 0
+            ;;
+        zkstack__dev__rich__account)
+            opts="-v -h --l1-account-private-key --amount --l1-rpc-url --verbose --chain --ignore-prerequisites --help [L2_ACCOUNT]"
+            if [[ ${cur} == -* || ${COMP_CWORD} -eq 3 ]] ; then
+                COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+                return 0
+            fi
+            case "${prev}" in
+                --l1-account-private-key)
+                    COMPREPLY=($(compgen -f "${cur}"))
+                    return 0
+                    ;;
+                --amount)
+                    COMPREPLY=($(compgen -f "${cur}"))
+                    return 0
+                    ;;
+                --l1-rpc-url)
+                    COMPREPLY=($(compgen -f "${cur}"))
+                    return 0
+                    ;;
+                --chain)
+                    COMPREPLY=($(compgen -f "${cur}"))
+                    return 0
+                    ;;
+                *)
+                    COMPREPLY=()
+                    ;;
+            esac
+            COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+            return 0
+            ;;
+        zkstack__dev__run__chain__upgrade)
+            opts="-v -h --dangerous-no-cross-check --dangerous-local-default-overrides --force-display-finalization-params --refund-recipient --upgrade-version --verbose --chain --ignore-prerequisites --help [UPGRADE_DESCRIPTION_PATH] [CHAIN_ID] [GW_CHAIN_ID] [L1_GAS_PRICE] [L1_RPC_URL] [L2_RPC_URL] [GW_RPC_URL] [SERVER_UPGRADE_TIMESTAMP]"
+            if [[ ${cur} == -* || ${COMP_CWORD} -eq 3 ]] ; then
+                COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+                return 0
+            fi
+            case "${prev}" in
+                --dangerous-no-cross-check)
+                    COMPREPLY=($(compgen -W "true false" -- "${cur}"))
+                    return 0
+                    ;;
+                --dangerous-local-default-overrides)
+                    COMPREPLY=($(compgen -W "true false" -- "${cur}"))
+                    return 0
+                    ;;
+                --force-display-finalization-params)
+                    COMPREPLY=($(compgen -W "true false" -- "${cur}"))
+                    return 0
+                    ;;
+                --refund-recipient)
+                    COMPREPLY=($(compgen -f "${cur}"))
+                    return 0
+                    ;;
+                --upgrade-version)
+                    COMPREPLY=($(compgen -W "v29-interop-a-ff v28-1vk v28-1vk-era" -- "${cur}"))
+                    return 0
+                    ;;
+                --chain)
+                    COMPREPLY=($(compgen -f "${cur}"))
+                    return 0
+                    ;;
+                *)
+                    COMPREPLY=()
+                    ;;
+            esac
+            COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+            return 0
+            ;;
+        zkstack__dev__run__ecosystem__upgrade)
+            opts="-a -v -h --zksync-os --update-submodules --skip-contract-compilation-override --l1-rpc-url --verify --verifier --verifier-url --verifier-api-key --resume --zksync --min-balance --non-interactive --ignore-balance-check --additional-args --upgrade-version --ecosystem-upgrade-stage --server-command --verbose --chain --ignore-prerequisites --help"
+            if [[ ${cur} == -* || ${COMP_CWORD} -eq 3 ]] ; then
+                COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+                return 0
+            fi
+            case "${prev}" in
+                --update-submodules)
+                    COMPREPLY=($(compgen -W "true false" -- "${cur}"))
+                    return 0
+                    ;;
+                --l1-rpc-url)
+                    COMPREPLY=($(compgen -f "${cur}"))
+                    return 0
+                    ;;
+                --verify)
+                    COMPREPLY=($(compgen -W "true false" -- "${cur}"))
+                    return 0
+                    ;;
+                --verifier)
+                    COMPREPLY=($(compgen -W "etherscan sourcify blockscout oklink" -- "${cur}"))
+                    return 0
+                    ;;
+                --verifier-url)
                     COMPREPLY=($(compgen -f "${cur}"))
                     return 0
                     ;;
-                --chain)
+                --verifier-api-key)
                     COMPREPLY=($(compgen -f "${cur}"))
                     return 0
                     ;;
-                *)
-                    COMPREPLY=()
+                --min-balance)
+                    COMPREPLY=($(compgen -f "${cur}"))
+                    return 0
                     ;;
-            esac
-            COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
-            return 0
-            ;;
-        zkstack__dev__prover__insert__version)
-            opts="-v -h --default --version --snark-wrapper --fflonk-snark-wrapper --verbose --chain --ignore-prerequisites --help"
-            if [[ ${cur} == -* || ${COMP_CWORD} -eq 4 ]] ; then
-                COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
-                return 0
-            fi
-            case "${prev}" in
-                --version)
+                --additional-args)
                     COMPREPLY=($(compgen -f "${cur}"))
                     return 0
                     ;;
-                --snark-wrapper)
+                -a)
                     COMPREPLY=($(compgen -f "${cur}"))
                     return 0
                     ;;
-                --fflonk-snark-wrapper)
+                --upgrade-version)
+                    COMPREPLY=($(compgen -W "v29-interop-a-ff v28-1vk v28-1vk-era" -- "${cur}"))
+                    return 0
+                    ;;
+                --ecosystem-upgrade-stage)
+                    COMPREPLY=($(compgen -W "no-governance-prepare ecosystem-admin governance-stage0 governance-stage1 governance-stage2 no-governance-stage2" -- "${cur}"))
+                    return 0
+                    ;;
+                --server-command)
                     COMPREPLY=($(compgen -f "${cur}"))
                     return 0
                     ;;
@@ -5543,22 +6772,34 @@ _zkstack() {
             COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
             return 0
             ;;
-        zkstack__dev__rich__account)
-            opts="-v -h --l1-account-private-key --amount --l1-rpc-url --verbose --chain --ignore-prerequisites --help [L2_ACCOUNT]"
+        zkstack__dev__run__v29__chain__upgrade)
+            opts="-v -h --dangerous-no-cross-check --dangerous-local-default-overrides --force-display-finalization-params --refund-recipient --operator --blob-operator --verbose --chain --ignore-prerequisites --help [UPGRADE_DESCRIPTION_PATH] [CHAIN_ID] [GW_CHAIN_ID] [L1_GAS_PRICE] [L1_RPC_URL] [L2_RPC_URL] [GW_RPC_URL] [SERVER_UPGRADE_TIMESTAMP]"
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 3 ]] ; then
                 COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
                 return 0
             fi
             case "${prev}" in
-                --l1-account-private-key)
+                --dangerous-no-cross-check)
+                    COMPREPLY=($(compgen -W "true false" -- "${cur}"))
+                    return 0
+                    ;;
+                --dangerous-local-default-overrides)
+                    COMPREPLY=($(compgen -W "true false" -- "${cur}"))
+                    return 0
+                    ;;
+                --force-display-finalization-params)
+                    COMPREPLY=($(compgen -W "true false" -- "${cur}"))
+                    return 0
+                    ;;
+                --refund-recipient)
                     COMPREPLY=($(compgen -f "${cur}"))
                     return 0
                     ;;
-                --amount)
+                --operator)
                     COMPREPLY=($(compgen -f "${cur}"))
                     return 0
                     ;;
-                --l1-rpc-url)
+                --blob-operator)
                     COMPREPLY=($(compgen -f "${cur}"))
                     return 0
                     ;;
@@ -6291,8 +7532,34 @@ _zkstack() {
             COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
             return 0
             ;;
+        zkstack__dev__v27__evm__interpreter__upgrade__calldata)
+            opts="-v -h --dangerous-no-cross-check --force-display-finalization-params --verbose --chain --ignore-prerequisites --help <UPGRADE_DESCRIPTION_PATH> <CHAIN_ID> <L1_RPC_URL> <L2_RPC_URL> <SERVER_UPGRADE_TIMESTAMP>"
+            if [[ ${cur} == -* || ${COMP_CWORD} -eq 3 ]] ; then
+                COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+                return 0
+            fi
+            case "${prev}" in
+                --dangerous-no-cross-check)
+                    COMPREPLY=($(compgen -W "true false" -- "${cur}"))
+                    return 0
+                    ;;
+                --force-display-finalization-params)
+                    COMPREPLY=($(compgen -W "true false" -- "${cur}"))
+                    return 0
+                    ;;
+                --chain)
+                    COMPREPLY=($(compgen -f "${cur}"))
+                    return 0
+                    ;;
+                *)
+                    COMPREPLY=()
+                    ;;
+            esac
+            COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+            return 0
+            ;;
         zkstack__ecosystem)
-            opts="-v -h --verbose --chain --ignore-prerequisites --help create build-transactions init change-default-chain setup-observability help"
+            opts="-v -h --verbose --chain --ignore-prerequisites --help create build-transactions init init-core-contracts change-default-chain setup-observability register-ctm help"
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 2 ]] ; then
                 COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
                 return 0
@@ -6310,20 +7577,24 @@ _zkstack() {
             return 0
             ;;
         zkstack__ecosystem__build__transactions)
-            opts="-o -a -v -h --sender --l1-rpc-url --out --verify --verifier --verifier-url --verifier-api-key --resume --zksync --additional-args --verbose --chain --ignore-prerequisites --help"
+            opts="-o -a -v -h --zksync-os --update-submodules --skip-contract-compilation-override --l1-rpc-url --sender --out --verify --verifier --verifier-url --verifier-api-key --resume --zksync --min-balance --non-interactive --ignore-balance-check --additional-args --bridgehub --verbose --chain --ignore-prerequisites --help"
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 3 ]] ; then
                 COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
                 return 0
             fi
             case "${prev}" in
-                --sender)
-                    COMPREPLY=($(compgen -f "${cur}"))
+                --update-submodules)
+                    COMPREPLY=($(compgen -W "true false" -- "${cur}"))
                     return 0
                     ;;
                 --l1-rpc-url)
                     COMPREPLY=($(compgen -f "${cur}"))
                     return 0
                     ;;
+                --sender)
+                    COMPREPLY=($(compgen -f "${cur}"))
+                    return 0
+                    ;;
                 --out)
                     COMPREPLY=($(compgen -f "${cur}"))
                     return 0
@@ -6348,6 +7619,10 @@ _zkstack() {
                     COMPREPLY=($(compgen -f "${cur}"))
                     return 0
                     ;;
+                --min-balance)
+                    COMPREPLY=($(compgen -f "${cur}"))
+                    return 0
+                    ;;
                 --additional-args)
                     COMPREPLY=($(compgen -f "${cur}"))
                     return 0
@@ -6356,6 +7631,10 @@ _zkstack() {
                     COMPREPLY=($(compgen -f "${cur}"))
                     return 0
                     ;;
+                --bridgehub)
+                    COMPREPLY=($(compgen -f "${cur}"))
+                    return 0
+                    ;;
                 --chain)
                     COMPREPLY=($(compgen -f "${cur}"))
                     return 0
@@ -6386,7 +7665,7 @@ _zkstack() {
             return 0
             ;;
         zkstack__ecosystem__create)
-            opts="-v -h --ecosystem-name --l1-network --link-to-code --chain-name --chain-id --prover-mode --wallet-creation --wallet-path --l1-batch-commit-data-generator-mode --base-token-address --base-token-price-nominator --base-token-price-denominator --set-as-default --legacy-bridge --evm-emulator --update-submodules --tight-ports --start-containers --verbose --chain --ignore-prerequisites --help"
+            opts="-v -h --ecosystem-name --l1-network --link-to-code --chain-name --chain-id --prover-mode --wallet-creation --wallet-path --l1-batch-commit-data-generator-mode --base-token-address --base-token-price-nominator --base-token-price-denominator --set-as-default --legacy-bridge --evm-emulator --tight-ports --zksync-os --start-containers --verbose --chain --ignore-prerequisites --help"
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 3 ]] ; then
                 COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
                 return 0
@@ -6462,10 +7741,6 @@ _zkstack() {
                     COMPREPLY=($(compgen -W "true false" -- "${cur}"))
                     return 0
                     ;;
-                --update-submodules)
-                    COMPREPLY=($(compgen -W "true false" -- "${cur}"))
-                    return 0
-                    ;;
                 --start-containers)
                     COMPREPLY=($(compgen -W "true false" -- "${cur}"))
                     return 0
@@ -6482,7 +7757,7 @@ _zkstack() {
             return 0
             ;;
         zkstack__ecosystem__help)
-            opts="create build-transactions init change-default-chain setup-observability help"
+            opts="create build-transactions init init-core-contracts change-default-chain setup-observability register-ctm help"
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 3 ]] ; then
                 COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
                 return 0
@@ -6519,59 +7794,193 @@ _zkstack() {
                 *)
                     COMPREPLY=()
                     ;;
-            esac
-            COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
-            return 0
-            ;;
-        zkstack__ecosystem__help__create)
-            opts=""
-            if [[ ${cur} == -* || ${COMP_CWORD} -eq 4 ]] ; then
-                COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
-                return 0
-            fi
-            case "${prev}" in
-                *)
-                    COMPREPLY=()
+            esac
+            COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+            return 0
+            ;;
+        zkstack__ecosystem__help__create)
+            opts=""
+            if [[ ${cur} == -* || ${COMP_CWORD} -eq 4 ]] ; then
+                COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+                return 0
+            fi
+            case "${prev}" in
+                *)
+                    COMPREPLY=()
+                    ;;
+            esac
+            COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+            return 0
+            ;;
+        zkstack__ecosystem__help__help)
+            opts=""
+            if [[ ${cur} == -* || ${COMP_CWORD} -eq 4 ]] ; then
+                COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+                return 0
+            fi
+            case "${prev}" in
+                *)
+                    COMPREPLY=()
+                    ;;
+            esac
+            COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+            return 0
+            ;;
+        zkstack__ecosystem__help__init)
+            opts=""
+            if [[ ${cur} == -* || ${COMP_CWORD} -eq 4 ]] ; then
+                COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+                return 0
+            fi
+            case "${prev}" in
+                *)
+                    COMPREPLY=()
+                    ;;
+            esac
+            COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+            return 0
+            ;;
+        zkstack__ecosystem__help__init__core__contracts)
+            opts=""
+            if [[ ${cur} == -* || ${COMP_CWORD} -eq 4 ]] ; then
+                COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+                return 0
+            fi
+            case "${prev}" in
+                *)
+                    COMPREPLY=()
+                    ;;
+            esac
+            COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+            return 0
+            ;;
+        zkstack__ecosystem__help__register__ctm)
+            opts=""
+            if [[ ${cur} == -* || ${COMP_CWORD} -eq 4 ]] ; then
+                COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+                return 0
+            fi
+            case "${prev}" in
+                *)
+                    COMPREPLY=()
+                    ;;
+            esac
+            COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+            return 0
+            ;;
+        zkstack__ecosystem__help__setup__observability)
+            opts=""
+            if [[ ${cur} == -* || ${COMP_CWORD} -eq 4 ]] ; then
+                COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+                return 0
+            fi
+            case "${prev}" in
+                *)
+                    COMPREPLY=()
+                    ;;
+            esac
+            COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+            return 0
+            ;;
+        zkstack__ecosystem__init)
+            opts="-a -d -o -v -h --zksync-os --update-submodules --skip-contract-compilation-override --l1-rpc-url --deploy-ecosystem --deploy-erc20 --ecosystem-contracts-path --verify --verifier --verifier-url --verifier-api-key --resume --zksync --min-balance --non-interactive --ignore-balance-check --additional-args --deploy-paymaster --server-db-url --server-db-name --dont-drop --ecosystem-only --dev --observability --no-port-reallocation --validium-type --support-l2-legacy-shared-bridge-test --make-permanent-rollup --skip-priority-txs --server-command --no-genesis --skip-l1-check --max-block-age-secs --skip-network-check --verbose --chain --ignore-prerequisites --help"
+            if [[ ${cur} == -* || ${COMP_CWORD} -eq 3 ]] ; then
+                COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+                return 0
+            fi
+            case "${prev}" in
+                --update-submodules)
+                    COMPREPLY=($(compgen -W "true false" -- "${cur}"))
+                    return 0
+                    ;;
+                --l1-rpc-url)
+                    COMPREPLY=($(compgen -f "${cur}"))
+                    return 0
+                    ;;
+                --deploy-ecosystem)
+                    COMPREPLY=($(compgen -W "true false" -- "${cur}"))
+                    return 0
+                    ;;
+                --deploy-erc20)
+                    COMPREPLY=($(compgen -W "true false" -- "${cur}"))
+                    return 0
+                    ;;
+                --ecosystem-contracts-path)
+                    COMPREPLY=($(compgen -f "${cur}"))
+                    return 0
+                    ;;
+                --verify)
+                    COMPREPLY=($(compgen -W "true false" -- "${cur}"))
+                    return 0
+                    ;;
+                --verifier)
+                    COMPREPLY=($(compgen -W "etherscan sourcify blockscout oklink" -- "${cur}"))
+                    return 0
+                    ;;
+                --verifier-url)
+                    COMPREPLY=($(compgen -f "${cur}"))
+                    return 0
+                    ;;
+                --verifier-api-key)
+                    COMPREPLY=($(compgen -f "${cur}"))
+                    return 0
+                    ;;
+                --min-balance)
+                    COMPREPLY=($(compgen -f "${cur}"))
+                    return 0
+                    ;;
+                --additional-args)
+                    COMPREPLY=($(compgen -f "${cur}"))
+                    return 0
+                    ;;
+                -a)
+                    COMPREPLY=($(compgen -f "${cur}"))
+                    return 0
+                    ;;
+                --deploy-paymaster)
+                    COMPREPLY=($(compgen -W "true false" -- "${cur}"))
+                    return 0
+                    ;;
+                --server-db-url)
+                    COMPREPLY=($(compgen -f "${cur}"))
+                    return 0
+                    ;;
+                --server-db-name)
+                    COMPREPLY=($(compgen -f "${cur}"))
+                    return 0
+                    ;;
+                --observability)
+                    COMPREPLY=($(compgen -W "true false" -- "${cur}"))
+                    return 0
+                    ;;
+                -o)
+                    COMPREPLY=($(compgen -W "true false" -- "${cur}"))
+                    return 0
+                    ;;
+                --validium-type)
+                    COMPREPLY=($(compgen -W "no-da avail eigen-da" -- "${cur}"))
+                    return 0
+                    ;;
+                --support-l2-legacy-shared-bridge-test)
+                    COMPREPLY=($(compgen -W "true false" -- "${cur}"))
+                    return 0
                     ;;
-            esac
-            COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
-            return 0
-            ;;
-        zkstack__ecosystem__help__help)
-            opts=""
-            if [[ ${cur} == -* || ${COMP_CWORD} -eq 4 ]] ; then
-                COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
-                return 0
-            fi
-            case "${prev}" in
-                *)
-                    COMPREPLY=()
+                --make-permanent-rollup)
+                    COMPREPLY=($(compgen -W "true false" -- "${cur}"))
+                    return 0
                     ;;
-            esac
-            COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
-            return 0
-            ;;
-        zkstack__ecosystem__help__init)
-            opts=""
-            if [[ ${cur} == -* || ${COMP_CWORD} -eq 4 ]] ; then
-                COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
-                return 0
-            fi
-            case "${prev}" in
-                *)
-                    COMPREPLY=()
+                --server-command)
+                    COMPREPLY=($(compgen -f "${cur}"))
+                    return 0
+                    ;;
+                --max-block-age-secs)
+                    COMPREPLY=($(compgen -f "${cur}"))
+                    return 0
+                    ;;
+                --chain)
+                    COMPREPLY=($(compgen -f "${cur}"))
+                    return 0
                     ;;
-            esac
-            COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
-            return 0
-            ;;
-        zkstack__ecosystem__help__setup__observability)
-            opts=""
-            if [[ ${cur} == -* || ${COMP_CWORD} -eq 4 ]] ; then
-                COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
-                return 0
-            fi
-            case "${prev}" in
                 *)
                     COMPREPLY=()
                     ;;
@@ -6579,27 +7988,23 @@ _zkstack() {
             COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
             return 0
             ;;
-        zkstack__ecosystem__init)
-            opts="-a -d -o -v -h --deploy-erc20 --deploy-ecosystem --ecosystem-contracts-path --l1-rpc-url --verify --verifier --verifier-url --verifier-api-key --resume --zksync --additional-args --deploy-paymaster --server-db-url --server-db-name --dont-drop --ecosystem-only --dev --observability --no-port-reallocation --update-submodules --validium-type --support-l2-legacy-shared-bridge-test --make-permanent-rollup --skip-contract-compilation-override --server-command --verbose --chain --ignore-prerequisites --help"
+        zkstack__ecosystem__init__core__contracts)
+            opts="-a -v -h --zksync-os --update-submodules --skip-contract-compilation-override --l1-rpc-url --deploy-erc20 --verify --verifier --verifier-url --verifier-api-key --resume --zksync --min-balance --non-interactive --ignore-balance-check --additional-args --dev --support-l2-legacy-shared-bridge-test --verbose --chain --ignore-prerequisites --help"
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 3 ]] ; then
                 COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
                 return 0
             fi
             case "${prev}" in
-                --deploy-erc20)
-                    COMPREPLY=($(compgen -W "true false" -- "${cur}"))
-                    return 0
-                    ;;
-                --deploy-ecosystem)
+                --update-submodules)
                     COMPREPLY=($(compgen -W "true false" -- "${cur}"))
                     return 0
                     ;;
-                --ecosystem-contracts-path)
+                --l1-rpc-url)
                     COMPREPLY=($(compgen -f "${cur}"))
                     return 0
                     ;;
-                --l1-rpc-url)
-                    COMPREPLY=($(compgen -f "${cur}"))
+                --deploy-erc20)
+                    COMPREPLY=($(compgen -W "true false" -- "${cur}"))
                     return 0
                     ;;
                 --verify)
@@ -6618,6 +8023,10 @@ _zkstack() {
                     COMPREPLY=($(compgen -f "${cur}"))
                     return 0
                     ;;
+                --min-balance)
+                    COMPREPLY=($(compgen -f "${cur}"))
+                    return 0
+                    ;;
                 --additional-args)
                     COMPREPLY=($(compgen -f "${cur}"))
                     return 0
@@ -6626,43 +8035,73 @@ _zkstack() {
                     COMPREPLY=($(compgen -f "${cur}"))
                     return 0
                     ;;
-                --deploy-paymaster)
+                --support-l2-legacy-shared-bridge-test)
                     COMPREPLY=($(compgen -W "true false" -- "${cur}"))
                     return 0
                     ;;
-                --server-db-url)
+                --chain)
                     COMPREPLY=($(compgen -f "${cur}"))
                     return 0
                     ;;
-                --server-db-name)
+                *)
+                    COMPREPLY=()
+                    ;;
+            esac
+            COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+            return 0
+            ;;
+        zkstack__ecosystem__register__ctm)
+            opts="-a -v -h --zksync-os --update-submodules --skip-contract-compilation-override --l1-rpc-url --verify --verifier --verifier-url --verifier-api-key --resume --zksync --min-balance --non-interactive --ignore-balance-check --additional-args --dev --only-save-calldata --bridgehub --ctm --verbose --chain --ignore-prerequisites --help"
+            if [[ ${cur} == -* || ${COMP_CWORD} -eq 3 ]] ; then
+                COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+                return 0
+            fi
+            case "${prev}" in
+                --update-submodules)
+                    COMPREPLY=($(compgen -W "true false" -- "${cur}"))
+                    return 0
+                    ;;
+                --l1-rpc-url)
                     COMPREPLY=($(compgen -f "${cur}"))
                     return 0
                     ;;
-                --observability)
+                --verify)
                     COMPREPLY=($(compgen -W "true false" -- "${cur}"))
                     return 0
                     ;;
-                -o)
-                    COMPREPLY=($(compgen -W "true false" -- "${cur}"))
+                --verifier)
+                    COMPREPLY=($(compgen -W "etherscan sourcify blockscout oklink" -- "${cur}"))
                     return 0
                     ;;
-                --update-submodules)
-                    COMPREPLY=($(compgen -W "true false" -- "${cur}"))
+                --verifier-url)
+                    COMPREPLY=($(compgen -f "${cur}"))
                     return 0
                     ;;
-                --validium-type)
-                    COMPREPLY=($(compgen -W "no-da avail eigen-da" -- "${cur}"))
+                --verifier-api-key)
+                    COMPREPLY=($(compgen -f "${cur}"))
                     return 0
                     ;;
-                --support-l2-legacy-shared-bridge-test)
-                    COMPREPLY=($(compgen -W "true false" -- "${cur}"))
+                --min-balance)
+                    COMPREPLY=($(compgen -f "${cur}"))
                     return 0
                     ;;
-                --make-permanent-rollup)
+                --additional-args)
+                    COMPREPLY=($(compgen -f "${cur}"))
+                    return 0
+                    ;;
+                -a)
+                    COMPREPLY=($(compgen -f "${cur}"))
+                    return 0
+                    ;;
+                --only-save-calldata)
                     COMPREPLY=($(compgen -W "true false" -- "${cur}"))
                     return 0
                     ;;
-                --server-command)
+                --bridgehub)
+                    COMPREPLY=($(compgen -f "${cur}"))
+                    return 0
+                    ;;
+                --ctm)
                     COMPREPLY=($(compgen -f "${cur}"))
                     return 0
                     ;;
@@ -7084,7 +8523,7 @@ _zkstack() {
             return 0
             ;;
         zkstack__help)
-            opts="autocomplete ecosystem chain dev prover server external-node containers contract-verifier portal private-rpc explorer consensus update markdown help"
+            opts="autocomplete ecosystem ctm chain dev prover server external-node containers contract-verifier portal private-rpc explorer consensus update markdown help"
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 2 ]] ; then
                 COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
                 return 0
@@ -7112,7 +8551,7 @@ _zkstack() {
             return 0
             ;;
         zkstack__help__chain)
-            opts="create build-transactions init genesis register-chain deploy-l2-contracts accept-chain-ownership deploy-consensus-registry deploy-multicall3 deploy-timestamp-asserter deploy-l2da-validator deploy-upgrader deploy-paymaster update-token-multiplier-setter set-transaction-filterer-calldata set-da-validator-pair-calldata enable-evm-emulator set-pubdata-pricing-mode set-da-validator-pair gateway"
+            opts="create build-transactions init genesis wait-for-genesis register-chain deploy-l2-contracts accept-chain-ownership deploy-consensus-registry deploy-multicall3 deploy-timestamp-asserter deploy-l2da-validator deploy-upgrader deploy-paymaster update-token-multiplier-setter set-transaction-filterer-calldata set-da-validator-pair-calldata build-admin-batch enable-evm-emulator set-pubdata-pricing-mode set-da-validator-pair upgrade-da-validator show-fee-config gateway"
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 3 ]] ; then
                 COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
                 return 0
@@ -7139,6 +8578,20 @@ _zkstack() {
             COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
             return 0
             ;;
+        zkstack__help__chain__build__admin__batch)
+            opts=""
+            if [[ ${cur} == -* || ${COMP_CWORD} -eq 4 ]] ; then
+                COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+                return 0
+            fi
+            case "${prev}" in
+                *)
+                    COMPREPLY=()
+                    ;;
+            esac
+            COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+            return 0
+            ;;
         zkstack__help__chain__build__transactions)
             opts=""
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 4 ]] ; then
@@ -7280,7 +8733,7 @@ _zkstack() {
             return 0
             ;;
         zkstack__help__chain__gateway)
-            opts="grant-gateway-transaction-filterer-whitelist-calldata notify-about-to-gateway-update-calldata notify-about-from-gateway-update-calldata migrate-to-gateway-calldata migrate-from-gateway-calldata finalize-chain-migration-from-gateway convert-to-gateway migrate-to-gateway migrate-from-gateway notify-about-to-gateway-update notify-about-from-gateway-update"
+            opts="grant-gateway-transaction-filterer-whitelist-calldata notify-about-to-gateway-update-calldata notify-about-from-gateway-update-calldata migrate-to-gateway-calldata migrate-from-gateway-calldata finalize-chain-migration-from-gateway create-tx-filterer convert-to-gateway migrate-to-gateway finalize-chain-migration-to-gateway migrate-from-gateway notify-about-to-gateway-update notify-about-from-gateway-update"
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 4 ]] ; then
                 COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
                 return 0
@@ -7307,6 +8760,20 @@ _zkstack() {
             COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
             return 0
             ;;
+        zkstack__help__chain__gateway__create__tx__filterer)
+            opts=""
+            if [[ ${cur} == -* || ${COMP_CWORD} -eq 5 ]] ; then
+                COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+                return 0
+            fi
+            case "${prev}" in
+                *)
+                    COMPREPLY=()
+                    ;;
+            esac
+            COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+            return 0
+            ;;
         zkstack__help__chain__gateway__finalize__chain__migration__from__gateway)
             opts=""
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 5 ]] ; then
@@ -7321,6 +8788,20 @@ _zkstack() {
             COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
             return 0
             ;;
+        zkstack__help__chain__gateway__finalize__chain__migration__to__gateway)
+            opts=""
+            if [[ ${cur} == -* || ${COMP_CWORD} -eq 5 ]] ; then
+                COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+                return 0
+            fi
+            case "${prev}" in
+                *)
+                    COMPREPLY=()
+                    ;;
+            esac
+            COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+            return 0
+            ;;
         zkstack__help__chain__gateway__grant__gateway__transaction__filterer__whitelist__calldata)
             opts=""
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 5 ]] ; then
@@ -7587,6 +9068,20 @@ _zkstack() {
             COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
             return 0
             ;;
+        zkstack__help__chain__show__fee__config)
+            opts=""
+            if [[ ${cur} == -* || ${COMP_CWORD} -eq 4 ]] ; then
+                COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+                return 0
+            fi
+            case "${prev}" in
+                *)
+                    COMPREPLY=()
+                    ;;
+            esac
+            COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+            return 0
+            ;;
         zkstack__help__chain__update__token__multiplier__setter)
             opts=""
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 4 ]] ; then
@@ -7601,6 +9096,34 @@ _zkstack() {
             COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
             return 0
             ;;
+        zkstack__help__chain__upgrade__da__validator)
+            opts=""
+            if [[ ${cur} == -* || ${COMP_CWORD} -eq 4 ]] ; then
+                COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+                return 0
+            fi
+            case "${prev}" in
+                *)
+                    COMPREPLY=()
+                    ;;
+            esac
+            COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+            return 0
+            ;;
+        zkstack__help__chain__wait__for__genesis)
+            opts=""
+            if [[ ${cur} == -* || ${COMP_CWORD} -eq 4 ]] ; then
+                COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+                return 0
+            fi
+            case "${prev}" in
+                *)
+                    COMPREPLY=()
+                    ;;
+            esac
+            COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+            return 0
+            ;;
         zkstack__help__consensus)
             opts="set-validator-schedule set-schedule-activation-delay get-validator-schedule get-pending-validator-schedule wait-for-registry"
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 3 ]] ; then
@@ -7645,7 +9168,49 @@ _zkstack() {
             ;;
         zkstack__help__consensus__set__schedule__activation__delay)
             opts=""
-            if [[ ${cur} == -* || ${COMP_CWORD} -eq 4 ]] ; then
+            if [[ ${cur} == -* || ${COMP_CWORD} -eq 4 ]] ; then
+                COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+                return 0
+            fi
+            case "${prev}" in
+                *)
+                    COMPREPLY=()
+                    ;;
+            esac
+            COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+            return 0
+            ;;
+        zkstack__help__consensus__set__validator__schedule)
+            opts=""
+            if [[ ${cur} == -* || ${COMP_CWORD} -eq 4 ]] ; then
+                COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+                return 0
+            fi
+            case "${prev}" in
+                *)
+                    COMPREPLY=()
+                    ;;
+            esac
+            COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+            return 0
+            ;;
+        zkstack__help__consensus__wait__for__registry)
+            opts=""
+            if [[ ${cur} == -* || ${COMP_CWORD} -eq 4 ]] ; then
+                COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+                return 0
+            fi
+            case "${prev}" in
+                *)
+                    COMPREPLY=()
+                    ;;
+            esac
+            COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+            return 0
+            ;;
+        zkstack__help__containers)
+            opts=""
+            if [[ ${cur} == -* || ${COMP_CWORD} -eq 3 ]] ; then
                 COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
                 return 0
             fi
@@ -7657,9 +9222,9 @@ _zkstack() {
             COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
             return 0
             ;;
-        zkstack__help__consensus__set__validator__schedule)
-            opts=""
-            if [[ ${cur} == -* || ${COMP_CWORD} -eq 4 ]] ; then
+        zkstack__help__contract__verifier)
+            opts="build run wait init"
+            if [[ ${cur} == -* || ${COMP_CWORD} -eq 3 ]] ; then
                 COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
                 return 0
             fi
@@ -7671,7 +9236,7 @@ _zkstack() {
             COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
             return 0
             ;;
-        zkstack__help__consensus__wait__for__registry)
+        zkstack__help__contract__verifier__build)
             opts=""
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 4 ]] ; then
                 COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
@@ -7685,9 +9250,9 @@ _zkstack() {
             COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
             return 0
             ;;
-        zkstack__help__containers)
+        zkstack__help__contract__verifier__init)
             opts=""
-            if [[ ${cur} == -* || ${COMP_CWORD} -eq 3 ]] ; then
+            if [[ ${cur} == -* || ${COMP_CWORD} -eq 4 ]] ; then
                 COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
                 return 0
             fi
@@ -7699,9 +9264,9 @@ _zkstack() {
             COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
             return 0
             ;;
-        zkstack__help__contract__verifier)
-            opts="build run wait init"
-            if [[ ${cur} == -* || ${COMP_CWORD} -eq 3 ]] ; then
+        zkstack__help__contract__verifier__run)
+            opts=""
+            if [[ ${cur} == -* || ${COMP_CWORD} -eq 4 ]] ; then
                 COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
                 return 0
             fi
@@ -7713,7 +9278,7 @@ _zkstack() {
             COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
             return 0
             ;;
-        zkstack__help__contract__verifier__build)
+        zkstack__help__contract__verifier__wait)
             opts=""
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 4 ]] ; then
                 COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
@@ -7727,9 +9292,9 @@ _zkstack() {
             COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
             return 0
             ;;
-        zkstack__help__contract__verifier__init)
-            opts=""
-            if [[ ${cur} == -* || ${COMP_CWORD} -eq 4 ]] ; then
+        zkstack__help__ctm)
+            opts="init-new-ctm set-ctm-contracts"
+            if [[ ${cur} == -* || ${COMP_CWORD} -eq 3 ]] ; then
                 COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
                 return 0
             fi
@@ -7741,7 +9306,7 @@ _zkstack() {
             COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
             return 0
             ;;
-        zkstack__help__contract__verifier__run)
+        zkstack__help__ctm__init__new__ctm)
             opts=""
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 4 ]] ; then
                 COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
@@ -7755,7 +9320,7 @@ _zkstack() {
             COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
             return 0
             ;;
-        zkstack__help__contract__verifier__wait)
+        zkstack__help__ctm__set__ctm__contracts)
             opts=""
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 4 ]] ; then
                 COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
@@ -7770,7 +9335,7 @@ _zkstack() {
             return 0
             ;;
         zkstack__help__dev)
-            opts="database test clean snapshot lint fmt prover contracts config-writer send-transactions status generate-genesis init-test-wallet rich-account track-priority-ops"
+            opts="database test clean snapshot lint fmt prover contracts config-writer send-transactions status generate-genesis init-test-wallet rich-account track-priority-ops v27-evm-interpreter-upgrade-calldata generate-v28-upgrade-calldata generate-ecosystem-upgrade-calldata run-ecosystem-upgrade generate-chain-upgrade run-chain-upgrade run-v29-chain-upgrade generate-v29-chain-upgrade"
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 3 ]] ; then
                 COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
                 return 0
@@ -7993,6 +9558,34 @@ _zkstack() {
             COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
             return 0
             ;;
+        zkstack__help__dev__generate__chain__upgrade)
+            opts=""
+            if [[ ${cur} == -* || ${COMP_CWORD} -eq 4 ]] ; then
+                COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+                return 0
+            fi
+            case "${prev}" in
+                *)
+                    COMPREPLY=()
+                    ;;
+            esac
+            COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+            return 0
+            ;;
+        zkstack__help__dev__generate__ecosystem__upgrade__calldata)
+            opts=""
+            if [[ ${cur} == -* || ${COMP_CWORD} -eq 4 ]] ; then
+                COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+                return 0
+            fi
+            case "${prev}" in
+                *)
+                    COMPREPLY=()
+                    ;;
+            esac
+            COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+            return 0
+            ;;
         zkstack__help__dev__generate__genesis)
             opts=""
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 4 ]] ; then
@@ -8007,6 +9600,34 @@ _zkstack() {
             COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
             return 0
             ;;
+        zkstack__help__dev__generate__v28__upgrade__calldata)
+            opts=""
+            if [[ ${cur} == -* || ${COMP_CWORD} -eq 4 ]] ; then
+                COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+                return 0
+            fi
+            case "${prev}" in
+                *)
+                    COMPREPLY=()
+                    ;;
+            esac
+            COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+            return 0
+            ;;
+        zkstack__help__dev__generate__v29__chain__upgrade)
+            opts=""
+            if [[ ${cur} == -* || ${COMP_CWORD} -eq 4 ]] ; then
+                COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+                return 0
+            fi
+            case "${prev}" in
+                *)
+                    COMPREPLY=()
+                    ;;
+            esac
+            COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+            return 0
+            ;;
         zkstack__help__dev__init__test__wallet)
             opts=""
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 4 ]] ; then
@@ -8105,6 +9726,48 @@ _zkstack() {
             COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
             return 0
             ;;
+        zkstack__help__dev__run__chain__upgrade)
+            opts=""
+            if [[ ${cur} == -* || ${COMP_CWORD} -eq 4 ]] ; then
+                COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+                return 0
+            fi
+            case "${prev}" in
+                *)
+                    COMPREPLY=()
+                    ;;
+            esac
+            COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+            return 0
+            ;;
+        zkstack__help__dev__run__ecosystem__upgrade)
+            opts=""
+            if [[ ${cur} == -* || ${COMP_CWORD} -eq 4 ]] ; then
+                COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+                return 0
+            fi
+            case "${prev}" in
+                *)
+                    COMPREPLY=()
+                    ;;
+            esac
+            COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+            return 0
+            ;;
+        zkstack__help__dev__run__v29__chain__upgrade)
+            opts=""
+            if [[ ${cur} == -* || ${COMP_CWORD} -eq 4 ]] ; then
+                COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+                return 0
+            fi
+            case "${prev}" in
+                *)
+                    COMPREPLY=()
+                    ;;
+            esac
+            COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+            return 0
+            ;;
         zkstack__help__dev__send__transactions)
             opts=""
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 4 ]] ; then
@@ -8371,8 +10034,22 @@ _zkstack() {
             COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
             return 0
             ;;
+        zkstack__help__dev__v27__evm__interpreter__upgrade__calldata)
+            opts=""
+            if [[ ${cur} == -* || ${COMP_CWORD} -eq 4 ]] ; then
+                COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+                return 0
+            fi
+            case "${prev}" in
+                *)
+                    COMPREPLY=()
+                    ;;
+            esac
+            COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+            return 0
+            ;;
         zkstack__help__ecosystem)
-            opts="create build-transactions init change-default-chain setup-observability"
+            opts="create build-transactions init init-core-contracts change-default-chain setup-observability register-ctm"
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 3 ]] ; then
                 COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
                 return 0
@@ -8441,6 +10118,34 @@ _zkstack() {
             COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
             return 0
             ;;
+        zkstack__help__ecosystem__init__core__contracts)
+            opts=""
+            if [[ ${cur} == -* || ${COMP_CWORD} -eq 4 ]] ; then
+                COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+                return 0
+            fi
+            case "${prev}" in
+                *)
+                    COMPREPLY=()
+                    ;;
+            esac
+            COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+            return 0
+            ;;
+        zkstack__help__ecosystem__register__ctm)
+            opts=""
+            if [[ ${cur} == -* || ${COMP_CWORD} -eq 4 ]] ; then
+                COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+                return 0
+            fi
+            case "${prev}" in
+                *)
+                    COMPREPLY=()
+                    ;;
+            esac
+            COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+            return 0
+            ;;
         zkstack__help__ecosystem__setup__observability)
             opts=""
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 4 ]] ; then
@@ -8694,7 +10399,7 @@ _zkstack() {
             return 0
             ;;
         zkstack__help__prover)
-            opts="init setup-keys run init-bellman-cuda compressor-keys"
+            opts="init setup-keys run init-bellman-cuda compressor-keys deploy-proving-network"
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 3 ]] ; then
                 COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
                 return 0
@@ -8721,6 +10426,20 @@ _zkstack() {
             COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
             return 0
             ;;
+        zkstack__help__prover__deploy__proving__network)
+            opts=""
+            if [[ ${cur} == -* || ${COMP_CWORD} -eq 4 ]] ; then
+                COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+                return 0
+            fi
+            case "${prev}" in
+                *)
+                    COMPREPLY=()
+                    ;;
+            esac
+            COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+            return 0
+            ;;
         zkstack__help__prover__init)
             opts=""
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 4 ]] ; then
@@ -9026,7 +10745,7 @@ _zkstack() {
             return 0
             ;;
         zkstack__prover)
-            opts="-v -h --verbose --chain --ignore-prerequisites --help init setup-keys run init-bellman-cuda compressor-keys help"
+            opts="-v -h --verbose --chain --ignore-prerequisites --help init setup-keys run init-bellman-cuda compressor-keys deploy-proving-network help"
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 2 ]] ; then
                 COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
                 return 0
@@ -9065,8 +10784,62 @@ _zkstack() {
             COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
             return 0
             ;;
+        zkstack__prover__deploy__proving__network)
+            opts="-v -h --rpc-url --l1-rpc-url --fermah-address --lagrange-address --usdc-address --proof-manager-owner-address --proxy-owner-address --top-up-server-wallets --top-up-network-wallets --verbose --chain --ignore-prerequisites --help"
+            if [[ ${cur} == -* || ${COMP_CWORD} -eq 3 ]] ; then
+                COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+                return 0
+            fi
+            case "${prev}" in
+                --rpc-url)
+                    COMPREPLY=($(compgen -f "${cur}"))
+                    return 0
+                    ;;
+                --l1-rpc-url)
+                    COMPREPLY=($(compgen -f "${cur}"))
+                    return 0
+                    ;;
+                --fermah-address)
+                    COMPREPLY=($(compgen -f "${cur}"))
+                    return 0
+                    ;;
+                --lagrange-address)
+                    COMPREPLY=($(compgen -f "${cur}"))
+                    return 0
+                    ;;
+                --usdc-address)
+                    COMPREPLY=($(compgen -f "${cur}"))
+                    return 0
+                    ;;
+                --proof-manager-owner-address)
+                    COMPREPLY=($(compgen -f "${cur}"))
+                    return 0
+                    ;;
+                --proxy-owner-address)
+                    COMPREPLY=($(compgen -f "${cur}"))
+                    return 0
+                    ;;
+                --top-up-server-wallets)
+                    COMPREPLY=($(compgen -W "true false" -- "${cur}"))
+                    return 0
+                    ;;
+                --top-up-network-wallets)
+                    COMPREPLY=($(compgen -W "true false" -- "${cur}"))
+                    return 0
+                    ;;
+                --chain)
+                    COMPREPLY=($(compgen -f "${cur}"))
+                    return 0
+                    ;;
+                *)
+                    COMPREPLY=()
+                    ;;
+            esac
+            COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+            return 0
+            ;;
         zkstack__prover__help)
-            opts="init setup-keys run init-bellman-cuda compressor-keys help"
+            opts="init setup-keys run init-bellman-cuda compressor-keys deploy-proving-network help"
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 3 ]] ; then
                 COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
                 return 0
@@ -9093,6 +10866,20 @@ _zkstack() {
             COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
             return 0
             ;;
+        zkstack__prover__help__deploy__proving__network)
+            opts=""
+            if [[ ${cur} == -* || ${COMP_CWORD} -eq 4 ]] ; then
+                COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+                return 0
+            fi
+            case "${prev}" in
+                *)
+                    COMPREPLY=()
+                    ;;
+            esac
+            COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+            return 0
+            ;;
         zkstack__prover__help__help)
             opts=""
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 4 ]] ; then
@@ -9164,7 +10951,7 @@ _zkstack() {
             return 0
             ;;
         zkstack__prover__init)
-            opts="-u -d -v -h --dev --proof-store-dir --bucket-base-url --credentials-file --bucket-name --location --project-id --deploy-proving-networks --clone --bellman-cuda-dir --bellman-cuda --setup-compressor-key --path --region --mode --setup-keys --setup-database --prover-db-url --prover-db-name --use-default --dont-drop --verbose --chain --ignore-prerequisites --help"
+            opts="-u -d -v -h --dev --proof-store-dir --bucket-base-url --credentials-file --bucket-name --location --project-id --deploy-proving-network --rpc-url --l1-rpc-url --fermah-address --lagrange-address --usdc-address --proof-manager-owner-address --proxy-owner-address --top-up-server-wallets --top-up-network-wallets --clone --bellman-cuda-dir --bellman-cuda --setup-compressor-key --path --region --mode --setup-keys --setup-database --prover-db-url --prover-db-name --use-default --dont-drop --verbose --chain --ignore-prerequisites --help"
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 3 ]] ; then
                 COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
                 return 0
@@ -9194,7 +10981,43 @@ _zkstack() {
                     COMPREPLY=($(compgen -f "${cur}"))
                     return 0
                     ;;
-                --deploy-proving-networks)
+                --deploy-proving-network)
+                    COMPREPLY=($(compgen -W "true false" -- "${cur}"))
+                    return 0
+                    ;;
+                --rpc-url)
+                    COMPREPLY=($(compgen -f "${cur}"))
+                    return 0
+                    ;;
+                --l1-rpc-url)
+                    COMPREPLY=($(compgen -f "${cur}"))
+                    return 0
+                    ;;
+                --fermah-address)
+                    COMPREPLY=($(compgen -f "${cur}"))
+                    return 0
+                    ;;
+                --lagrange-address)
+                    COMPREPLY=($(compgen -f "${cur}"))
+                    return 0
+                    ;;
+                --usdc-address)
+                    COMPREPLY=($(compgen -f "${cur}"))
+                    return 0
+                    ;;
+                --proof-manager-owner-address)
+                    COMPREPLY=($(compgen -f "${cur}"))
+                    return 0
+                    ;;
+                --proxy-owner-address)
+                    COMPREPLY=($(compgen -f "${cur}"))
+                    return 0
+                    ;;
+                --top-up-server-wallets)
+                    COMPREPLY=($(compgen -W "true false" -- "${cur}"))
+                    return 0
+                    ;;
+                --top-up-network-wallets)
                     COMPREPLY=($(compgen -W "true false" -- "${cur}"))
                     return 0
                     ;;
@@ -9554,7 +11377,7 @@ _zkstack() {
             return 0
             ;;
         zkstack__update)
-            opts="-c -v -h --only-config --verbose --chain --ignore-prerequisites --help"
+            opts="-c -v -h --only-config --zksync-os --verbose --chain --ignore-prerequisites --help"
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 2 ]] ; then
                 COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
                 return 0
@@ -20,11 +20,16 @@ abigen!(
     r"[
     function getDAValidatorPair()(address,address)
     function getAdmin()(address)
+    function getPendingAdmin()(address)
     function getProtocolVersion()(uint256)
     function getTotalBatchesCommitted()(uint256)
     function getTotalBatchesVerified()(uint256)
     function getTotalBatchesExecuted()(uint256)
     function getPriorityQueueSize()(uint256)
+    function getPubdataPricingMode()(uint8)
+    function setTransactionFilterer(address)
+    function setDAValidatorPair(address,address)
+    function setPubdataPricingMode(uint8)
 ]"
 );
 
@@ -1,21 +1,70 @@
 use anyhow::Context;
 use xshell::Shell;
-use zkstack_cli_common::{forge::ForgeScriptArgs, logger, spinner::Spinner};
-use zkstack_cli_config::{ZkStackConfig, ZkStackConfigTrait};
+use zkstack_cli_common::{
+    ethereum::get_ethers_provider, forge::ForgeScriptArgs, logger, spinner::Spinner,
+};
+use zkstack_cli_config::{
+    traits::{FileConfigWithDefaultName, SaveConfigWithBasePath},
+    AcceptedAdmin, ChainStateCache, ZkStackConfig, ZkStackConfigTrait,
+};
+use zksync_basic_types::Address;
 
 use crate::{
+    abi::ZkChainAbi,
     admin_functions::accept_admin,
     messages::{
-        MSG_ACCEPTING_ADMIN_SPINNER, MSG_CHAIN_NOT_INITIALIZED, MSG_CHAIN_OWNERSHIP_TRANSFERRED,
+        msg_chain_admin_accepted_cached, msg_pending_admin_mismatch, MSG_ACCEPTING_ADMIN_SPINNER,
+        MSG_CHAIN_ADMIN_ALREADY_ACCEPTED, MSG_CHAIN_NOT_INITIALIZED,
+        MSG_CHAIN_OWNERSHIP_TRANSFERRED, MSG_CHECKING_PENDING_ADMIN_SPINNER, MSG_NO_PENDING_ADMIN,
     },
 };
 
 pub async fn run(args: ForgeScriptArgs, shell: &Shell) -> anyhow::Result<()> {
     let chain_config = ZkStackConfig::current_chain(shell).context(MSG_CHAIN_NOT_INITIALIZED)?;
     let contracts = chain_config.get_contracts_config()?;
+    let accepted_admin = AcceptedAdmin {
+        diamond_proxy: contracts.l1.diamond_proxy_addr,
+        chain_admin: contracts.l1.chain_admin_addr,
+    };
+    let mut chain_state = ChainStateCache::read_or_default(shell, &chain_config.configs)?;
+    if chain_state.accepted_admin.as_ref() == Some(&accepted_admin) {
+        logger::warn(msg_chain_admin_accepted_cached(
+            &ChainStateCache::get_path_with_base_path(&chain_config.configs),
+        ));
+        return Ok(());
+    }
+
     let secrets = chain_config.get_secrets_config().await?;
     let l1_rpc_url = secrets.l1_rpc_url()?;
 
+    let spinner = Spinner::new(MSG_CHECKING_PENDING_ADMIN_SPINNER);
+    let zk_chain = ZkChainAbi::new(
+        contracts.l1.diamond_proxy_addr,
+        get_ethers_provider(&l1_rpc_url)?,
+    );
+    let admin = zk_chain.get_admin().await?;
+    let pending_admin = zk_chain.get_pending_admin().await?;
+    spinner.finish();
+    match admin_state(admin, pending_admin, contracts.l1.chain_admin_addr) {
+        AdminState::Pending => {}
+        AdminState::Accepted => {
+            logger::warn(MSG_CHAIN_ADMIN_ALREADY_ACCEPTED);
+            chain_state.accepted_admin = Some(accepted_admin);
+            return chain_state.save_with_base_path(shell, &chain_config.configs);
+        }
+        AdminState::NoPendingAdmin => {
+            logger::warn(MSG_NO_PENDING_ADMIN);
+            return Ok(());
+        }
+        AdminState::PendingAdminMismatch => {
+            logger::warn(msg_pending_admin_mismatch(
+                pending_admin,
+                contracts.l1.chain_admin_addr,
+            ));
+            return Ok(());
+        }
+    }
+
     let spinner = Spinner::new(MSG_ACCEPTING_ADMIN_SPINNER);
     accept_admin(
         shell,
@@ -28,6 +77,82 @@ pub async fn run(args: ForgeScriptArgs, shell: &Shell) -> anyhow::Result<()> {
     )
     .await?;
     spinner.finish();
+    chain_state.accepted_admin = Some(accepted_admin);
+    chain_state.save_with_base_path(shell, &chain_config.configs)?;
     logger::success(MSG_CHAIN_OWNERSHIP_TRANSFERRED);
     Ok(())
 }
+
+#[derive(Debug, PartialEq)]
+enum AdminState {
+    /// The chain admin already holds the admin role.
+    Accepted,
+    /// The chain admin can accept the admin role.
+    Pending,
+    /// The admin role was never transferred, so accepting it would revert.
+    NoPendingAdmin,
+    /// The admin role was transferred to another address, so accepting it would revert.
+    PendingAdminMismatch,
+}
+
+fn admin_state(admin: Address, pending_admin: Address, chain_admin: Address) -> AdminState {
+    if admin == chain_admin {
+        AdminState::Accepted
+    } else if pending_admin == Address::zero() {
+        AdminState::NoPendingAdmin
+    } else if pending_admin != chain_admin {
+        AdminState::PendingAdminMismatch
+    } else {
+        AdminState::Pending
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_admin_state() {
+        let chain_admin = Address::repeat_byte(0x11);
+        let other = Address::repeat_byte(0x22);
+
+        assert_eq!(
+            admin_state(other, chain_admin, chain_admin),
+            AdminState::Pending
+        );
+        assert_eq!(
+            admin_state(chain_admin, Address::zero(), chain_admin),
+            AdminState::Accepted
+        );
+        assert_eq!(
+            admin_state(other, Address::zero(), chain_admin),
+            AdminState::NoPendingAdmin
+        );
+        assert_eq!(
+            admin_state(other, other, chain_admin),
+            AdminState::PendingAdminMismatch
+        );
+    }
+
+    #[test]
+    fn test_chain_state_cache() {
+        let shell = Shell::new().unwrap();
+        let dir = shell.create_temp_dir().unwrap();
+        assert_eq!(
+            ChainStateCache::read_or_default(&shell, dir.path()).unwrap(),
+            ChainStateCache::default()
+        );
+
+        let chain_state = ChainStateCache {
+            accepted_admin: Some(AcceptedAdmin {
+                diamond_proxy: Address::repeat_byte(0x11),
+                chain_admin: Address::repeat_byte(0x22),
+            }),
+        };
+        chain_state.save_with_base_path(&shell, dir.path()).unwrap();
+        assert_eq!(
+            ChainStateCache::read_or_default(&shell, dir.path()).unwrap(),
+            chain_state
+        );
+    }
+}
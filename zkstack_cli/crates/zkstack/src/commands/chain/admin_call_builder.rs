@@ -1,5 +1,6 @@
 use std::path::Path;
 
+use anyhow::Context;
 use ethers::{
     abi::{decode, Abi, ParamType, Token},
     types::Bytes,
@@ -8,12 +9,16 @@ use ethers::{
 use serde::Serialize;
 use xshell::Shell;
 use zkstack_cli_common::forge::ForgeScriptArgs;
+use zkstack_cli_types::L1BatchCommitmentMode;
 use zksync_types::{Address, U256};
 
-use crate::abi::{
-    CHAINADMINOWNABLEABI_ABI as CHAIN_ADMIN_OWNABLE_ABI,
-    CHAINTYPEMANAGERUPGRADEFNABI_ABI as CHAIN_TYPE_MANAGER_UPGRADE_ABI,
-    DIAMONDCUTABI_ABI as DIAMOND_CUT_ABI,
+use crate::{
+    abi::{
+        CHAINADMINOWNABLEABI_ABI as CHAIN_ADMIN_OWNABLE_ABI,
+        CHAINTYPEMANAGERUPGRADEFNABI_ABI as CHAIN_TYPE_MANAGER_UPGRADE_ABI,
+        DIAMONDCUTABI_ABI as DIAMOND_CUT_ABI, ZKCHAINABI_ABI as ZK_CHAIN_ABI,
+    },
+    messages::{MSG_DA_VALIDATOR_PAIR_SAME_ADDRESS_ERR, MSG_DA_VALIDATOR_PAIR_ZERO_ADDRESS_ERR},
 };
 
 #[derive(Debug, Clone, Serialize)]
@@ -187,6 +192,81 @@ impl AdminCallBuilder {
         self.calls.push(call);
     }
 
+    pub fn append_set_transaction_filterer(
+        &mut self,
+        diamond_proxy: Address,
+        transaction_filterer: Address,
+    ) -> anyhow::Result<()> {
+        self.append_zk_chain_call(
+            format!("Set transaction filterer to {transaction_filterer:?}"),
+            diamond_proxy,
+            "setTransactionFilterer",
+            &[Token::Address(transaction_filterer)],
+        )
+    }
+
+    pub fn append_set_da_validator_pair(
+        &mut self,
+        diamond_proxy: Address,
+        l1_da_validator: Address,
+        l2_da_validator: Address,
+    ) -> anyhow::Result<()> {
+        anyhow::ensure!(
+            !l1_da_validator.is_zero() && !l2_da_validator.is_zero(),
+            MSG_DA_VALIDATOR_PAIR_ZERO_ADDRESS_ERR
+        );
+        anyhow::ensure!(
+            l1_da_validator != l2_da_validator,
+            MSG_DA_VALIDATOR_PAIR_SAME_ADDRESS_ERR
+        );
+        self.append_zk_chain_call(
+            format!("Set DA validator pair to {l1_da_validator:?}, {l2_da_validator:?}"),
+            diamond_proxy,
+            "setDAValidatorPair",
+            &[
+                Token::Address(l1_da_validator),
+                Token::Address(l2_da_validator),
+            ],
+        )
+    }
+
+    pub fn append_set_pubdata_pricing_mode(
+        &mut self,
+        diamond_proxy: Address,
+        mode: L1BatchCommitmentMode,
+    ) -> anyhow::Result<()> {
+        self.append_zk_chain_call(
+            format!("Set pubdata pricing mode to {mode}"),
+            diamond_proxy,
+            "setPubdataPricingMode",
+            &[Token::Uint(pubdata_pricing_mode(mode).into())],
+        )
+    }
+
+    fn append_zk_chain_call(
+        &mut self,
+        description: String,
+        diamond_proxy: Address,
+        function: &str,
+        args: &[Token],
+    ) -> anyhow::Result<()> {
+        let data = ZK_CHAIN_ABI
+            .function(function)?
+            .encode_input(args)
+            .with_context(|| format!("failed to encode {function} call"))?;
+        self.calls.push(AdminCall {
+            description,
+            target: diamond_proxy,
+            data,
+            value: U256::zero(),
+        });
+        Ok(())
+    }
+
+    pub fn into_calls(self) -> Vec<AdminCall> {
+        self.calls
+    }
+
     pub fn to_json_string(&self) -> String {
         // Serialize with pretty printing
         serde_json::to_string_pretty(&self.calls).unwrap()
@@ -219,3 +299,43 @@ impl AdminCallBuilder {
         (data.to_vec(), sum)
     }
 }
+
+/// Maps the commitment mode to the value of the `PubdataPricingMode` enum in the contracts.
+fn pubdata_pricing_mode(mode: L1BatchCommitmentMode) -> u8 {
+    match mode {
+        L1BatchCommitmentMode::Rollup => 0,
+        L1BatchCommitmentMode::Validium => 1,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_append_set_pubdata_pricing_mode() -> anyhow::Result<()> {
+        let diamond_proxy = Address::repeat_byte(0x11);
+        let mut builder = AdminCallBuilder::new(vec![]);
+        builder.append_set_pubdata_pricing_mode(diamond_proxy, L1BatchCommitmentMode::Rollup)?;
+        builder.append_set_pubdata_pricing_mode(diamond_proxy, L1BatchCommitmentMode::Validium)?;
+
+        let function = ZK_CHAIN_ABI.function("setPubdataPricingMode")?;
+        let modes = builder
+            .into_calls()
+            .into_iter()
+            .map(|call| {
+                assert_eq!(call.target, diamond_proxy);
+                assert_eq!(call.data[..4], function.short_signature());
+                function.decode_input(&call.data[4..])
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        assert_eq!(
+            modes,
+            [
+                vec![Token::Uint(U256::zero())],
+                vec![Token::Uint(U256::one())]
+            ]
+        );
+        Ok(())
+    }
+}
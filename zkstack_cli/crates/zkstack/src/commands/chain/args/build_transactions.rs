@@ -1,6 +1,6 @@
 use std::path::PathBuf;
 
-use clap::Parser;
+use clap::{Parser, ValueEnum};
 use serde::{Deserialize, Serialize};
 use url::Url;
 use zkstack_cli_common::{config::global_config, forge::ForgeScriptArgs, Prompt};
@@ -13,6 +13,15 @@ use crate::{
 
 const CHAIN_SUBDIR: &str = "chain";
 
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, ValueEnum)]
+pub enum TransactionsOutputFormat {
+    /// Forge broadcast file as produced by the dry run
+    #[default]
+    Forge,
+    /// Transaction batch that can be imported into the Safe Transaction Builder
+    SafeJson,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, Parser)]
 pub struct BuildTransactionsArgs {
     /// Output directory for the generated files.
@@ -24,6 +33,9 @@ pub struct BuildTransactionsArgs {
     pub forge_args: ForgeScriptArgs,
     #[clap(long, help = MSG_L1_RPC_URL_HELP)]
     pub l1_rpc_url: Option<String>,
+    /// Format of the file with the built transactions
+    #[clap(long, value_enum, default_value_t)]
+    pub output_format: TransactionsOutputFormat,
 }
 
 impl BuildTransactionsArgs {
@@ -48,6 +60,7 @@ impl BuildTransactionsArgs {
                 .join(chain_name.unwrap_or(default_chain)),
             forge_args: self.forge_args,
             l1_rpc_url,
+            output_format: self.output_format,
         }
     }
 }
@@ -57,4 +70,5 @@ pub struct BuildTransactionsArgsFinal {
     pub out: PathBuf,
     pub forge_args: ForgeScriptArgs,
     pub l1_rpc_url: String,
+    pub output_format: TransactionsOutputFormat,
 }
@@ -32,7 +32,7 @@ use crate::{
 
 // We need to duplicate it for using enum inside the arguments
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, EnumIter, Display, ValueEnum)]
-enum L1BatchCommitmentModeInternal {
+pub enum L1BatchCommitmentModeInternal {
     Rollup,
     Validium,
 }
@@ -10,7 +10,8 @@ use crate::{
     defaults::{generate_db_names, DBNames, DATABASE_SERVER_URL},
     messages::{
         msg_server_db_name_prompt, msg_server_db_url_prompt, MSG_SERVER_COMMAND_HELP,
-        MSG_SERVER_DB_NAME_HELP, MSG_SERVER_DB_URL_HELP, MSG_USE_DEFAULT_DATABASES_HELP,
+        MSG_SERVER_DB_NAME_HELP, MSG_SERVER_DB_URL_HELP, MSG_SKIP_L1_CHECK_HELP,
+        MSG_USE_DEFAULT_DATABASES_HELP,
     },
 };
 
@@ -26,17 +27,20 @@ pub struct GenesisArgs {
     pub dont_drop: bool,
     #[clap(long, help = MSG_SERVER_COMMAND_HELP)]
     pub server_command: Option<String>,
+    #[clap(long, help = MSG_SKIP_L1_CHECK_HELP)]
+    pub skip_l1_check: bool,
 }
 
 impl GenesisArgs {
-    pub fn fill_values_with_prompt(self, config: &ChainConfig) -> GenesisArgsFinal {
-        let DBNames { server_name, .. } = generate_db_names(config);
+    pub async fn fill_values_with_prompt(self, config: &ChainConfig) -> GenesisArgsFinal {
+        let DBNames { server_name, .. } = generate_db_names(config).await;
         let chain_name = config.name.clone();
         if self.dev {
             GenesisArgsFinal {
                 server_db: DatabaseConfig::new(DATABASE_SERVER_URL.clone(), server_name),
                 dont_drop: self.dont_drop,
                 server_command: self.server_command,
+                skip_l1_check: self.skip_l1_check,
             }
         } else {
             let server_db_url = self.server_db_url.unwrap_or_else(|| {
@@ -56,6 +60,7 @@ impl GenesisArgs {
                 server_db: DatabaseConfig::new(server_db_url, server_db_name),
                 dont_drop: self.dont_drop,
                 server_command: self.server_command,
+                skip_l1_check: self.skip_l1_check,
             }
         }
     }
@@ -78,7 +83,7 @@ impl GenesisArgs {
         self.server_db_url = self.server_db_url.or(server_db_url);
         self.server_db_name = self.server_db_name.or(server_db_name);
 
-        Ok(self.fill_values_with_prompt(chain_config))
+        Ok(self.fill_values_with_prompt(chain_config).await)
     }
 
     pub fn reset_db_names(&mut self) {
@@ -92,4 +97,5 @@ pub struct GenesisArgsFinal {
     pub server_command: Option<String>,
     pub server_db: DatabaseConfig,
     pub dont_drop: bool,
+    pub skip_l1_check: bool,
 }
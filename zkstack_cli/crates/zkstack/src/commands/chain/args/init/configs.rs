@@ -1,3 +1,5 @@
+use std::time::Duration;
+
 use clap::Parser;
 use serde::{Deserialize, Serialize};
 use url::Url;
@@ -8,7 +10,7 @@ use zkstack_cli_types::L1Network;
 use crate::{
     commands::chain::args::{
         genesis::{GenesisArgs, GenesisArgsFinal},
-        init::{da_configs::ValidiumType, InitArgsFinal},
+        init::{da_configs::ValidiumType, InitArgsFinal, NetworkCheckArgs},
     },
     defaults::LOCAL_RPC_URL,
     messages::{
@@ -26,6 +28,9 @@ pub struct InitConfigsArgs {
     pub l1_rpc_url: Option<String>,
     #[clap(long, help = MSG_NO_PORT_REALLOCATION_HELP)]
     pub no_port_reallocation: bool,
+    #[clap(flatten)]
+    #[serde(flatten)]
+    pub network_check: NetworkCheckArgs,
 }
 
 #[derive(Debug, Clone)]
@@ -34,10 +39,12 @@ pub struct InitConfigsArgsFinal {
     pub l1_rpc_url: String,
     pub no_port_reallocation: bool,
     pub validium_config: Option<ValidiumType>,
+    pub skip_l1_check: bool,
+    pub max_l1_block_age: Option<Duration>,
 }
 
 impl InitConfigsArgs {
-    pub fn fill_values_with_prompt(self, config: &ChainConfig) -> InitConfigsArgsFinal {
+    pub async fn fill_values_with_prompt(self, config: &ChainConfig) -> InitConfigsArgsFinal {
         let l1_rpc_url = self.l1_rpc_url.unwrap_or_else(|| {
             let mut prompt = Prompt::new(MSG_L1_RPC_URL_PROMPT);
             if config.l1_network == L1Network::Localhost {
@@ -52,11 +59,14 @@ impl InitConfigsArgs {
                 .ask()
         });
 
+        let skip_l1_check = self.genesis_args.skip_l1_check;
         InitConfigsArgsFinal {
-            genesis_args: Some(self.genesis_args.fill_values_with_prompt(config)),
+            genesis_args: Some(self.genesis_args.fill_values_with_prompt(config).await),
             l1_rpc_url,
             no_port_reallocation: self.no_port_reallocation,
             validium_config: Some(ValidiumType::read()),
+            skip_l1_check,
+            max_l1_block_age: self.network_check.max_l1_block_age(config.l1_network),
         }
     }
 }
@@ -68,6 +78,8 @@ impl InitConfigsArgsFinal {
             l1_rpc_url: init_args.l1_rpc_url.clone(),
             no_port_reallocation: init_args.no_port_reallocation,
             validium_config: init_args.validium_config.clone(),
+            skip_l1_check: init_args.skip_l1_check,
+            max_l1_block_age: init_args.max_l1_block_age,
         }
     }
 }
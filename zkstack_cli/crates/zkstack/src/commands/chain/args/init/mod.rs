@@ -1,3 +1,5 @@
+use std::time::Duration;
+
 use clap::Parser;
 use serde::{Deserialize, Serialize};
 use url::Url;
@@ -10,12 +12,14 @@ use crate::{
         genesis::{GenesisArgs, GenesisArgsFinal},
         init::da_configs::ValidiumType,
     },
+    commands::chain::common::default_max_l1_block_age,
     defaults::LOCAL_RPC_URL,
     messages::{
         MSG_DEPLOY_PAYMASTER_PROMPT, MSG_DEV_ARG_HELP, MSG_L1_RPC_URL_HELP,
-        MSG_L1_RPC_URL_INVALID_ERR, MSG_NO_GENESIS, MSG_NO_PORT_REALLOCATION_HELP,
-        MSG_RPC_URL_PROMPT, MSG_SERVER_COMMAND_HELP, MSG_SERVER_DB_NAME_HELP,
-        MSG_SERVER_DB_URL_HELP,
+        MSG_L1_RPC_URL_INVALID_ERR, MSG_MAX_BLOCK_AGE_SECS_HELP, MSG_NO_GENESIS,
+        MSG_NO_PORT_REALLOCATION_HELP, MSG_RPC_URL_PROMPT, MSG_SERVER_COMMAND_HELP,
+        MSG_SERVER_DB_NAME_HELP, MSG_SERVER_DB_URL_HELP, MSG_SKIP_L1_CHECK_HELP,
+        MSG_SKIP_NETWORK_CHECK_HELP,
     },
 };
 
@@ -52,6 +56,32 @@ pub struct InitArgs {
     pub no_genesis: bool,
     #[clap(long, default_value_t = false, default_missing_value = "true")]
     pub skip_priority_txs: bool,
+    #[clap(long, help = MSG_SKIP_L1_CHECK_HELP)]
+    pub skip_l1_check: bool,
+    #[clap(flatten)]
+    #[serde(flatten)]
+    pub network_check: NetworkCheckArgs,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, Parser)]
+pub struct NetworkCheckArgs {
+    #[clap(long, help = MSG_MAX_BLOCK_AGE_SECS_HELP)]
+    pub max_block_age_secs: Option<u64>,
+    #[clap(long, help = MSG_SKIP_NETWORK_CHECK_HELP, conflicts_with = "max_block_age_secs")]
+    pub skip_network_check: bool,
+}
+
+impl NetworkCheckArgs {
+    /// Returns the maximum age of the latest L1 block, or `None` if the check is skipped.
+    pub fn max_l1_block_age(&self, l1_network: L1Network) -> Option<Duration> {
+        if self.skip_network_check {
+            return None;
+        }
+        Some(
+            self.max_block_age_secs
+                .map_or_else(|| default_max_l1_block_age(l1_network), Duration::from_secs),
+        )
+    }
 }
 
 impl InitArgs {
@@ -65,10 +95,13 @@ impl InitArgs {
             dev: self.dev,
             dont_drop: self.dont_drop,
             server_command: self.server_command.clone(),
+            // `chain init` checks the L1 chain ID while validating the configs,
+            // so genesis doesn't need to check it again.
+            skip_l1_check: true,
         })
     }
 
-    pub fn fill_values_with_prompt(self, config: &ChainConfig) -> InitArgsFinal {
+    pub async fn fill_values_with_prompt(self, config: &ChainConfig) -> InitArgsFinal {
         let genesis = match config.vm_option {
             VMOption::EraVM => self.get_genesis_args(),
             VMOption::ZKSyncOsVM => None,
@@ -114,15 +147,22 @@ impl InitArgs {
             _ => None,
         };
 
+        let genesis_args = match genesis {
+            Some(genesis) => Some(genesis.fill_values_with_prompt(config).await),
+            None => None,
+        };
+
         InitArgsFinal {
             forge_args: self.forge_args,
-            genesis_args: genesis.map(|genesis| genesis.fill_values_with_prompt(config)),
+            genesis_args,
             deploy_paymaster,
             l1_rpc_url,
             no_port_reallocation: self.no_port_reallocation,
             validium_config,
             make_permanent_rollup: self.make_permanent_rollup,
             skip_priority_txs: self.skip_priority_txs,
+            skip_l1_check: self.skip_l1_check,
+            max_l1_block_age: self.network_check.max_l1_block_age(config.l1_network),
         }
     }
 }
@@ -137,4 +177,6 @@ pub struct InitArgsFinal {
     pub validium_config: Option<ValidiumType>,
     pub make_permanent_rollup: bool,
     pub skip_priority_txs: bool,
+    pub skip_l1_check: bool,
+    pub max_l1_block_age: Option<Duration>,
 }
@@ -1,4 +1,4 @@
-use clap::Parser;
+use clap::{ArgAction, Parser};
 use serde::{Deserialize, Serialize};
 use zkstack_cli_common::forge::ForgeScriptArgs;
 
@@ -7,6 +7,9 @@ pub struct SetPubdataPricingModeArgs {
     /// Whether set pubdata to rollup or validium (if false)
     #[arg(long, short)]
     pub rollup: Option<bool>,
+    /// Check the current on-chain mode first and skip the transaction if it already matches
+    #[clap(long, default_value_t = true, default_missing_value = "true", num_args = 0..=1, action = ArgAction::Set)]
+    pub check_current: bool,
     /// All ethereum environment related arguments
     #[clap(flatten)]
     #[serde(flatten)]
@@ -0,0 +1,161 @@
+use clap::Parser;
+use ethers::providers::Middleware;
+use serde::{Deserialize, Serialize};
+use zkstack_cli_common::ethereum::get_ethers_provider;
+use zksync_types::Address;
+
+use super::{
+    admin_call_builder::AdminCallBuilder, args::create::L1BatchCommitmentModeInternal,
+    utils::display_admin_script_output,
+};
+use crate::{
+    abi::{BridgehubAbi, ZkChainAbi},
+    admin_functions::AdminScriptOutput,
+    messages::{
+        MSG_ADMIN_BATCH_CHAIN_NOT_ON_L1_ERR, MSG_ADMIN_BATCH_EMPTY_ERR,
+        MSG_ADMIN_BATCH_NOT_REGISTERED_ERR,
+    },
+};
+
+#[derive(Debug, Serialize, Deserialize, Parser)]
+pub struct BuildAdminBatchArgs {
+    /// Bridgehub address
+    pub bridgehub_address: Address,
+
+    /// The ID of the ZK chain
+    pub chain_id: u64,
+
+    /// L1 RPC URL, used to look up the chain's diamond proxy and admin
+    pub l1_rpc_url: String,
+
+    /// Set the transaction filterer of the chain
+    #[clap(long)]
+    pub transaction_filterer: Option<Address>,
+
+    /// Set the DA validator pair of the chain, this is the L1 part of the pair
+    #[clap(long, requires = "l2_da_validator")]
+    pub l1_da_validator: Option<Address>,
+
+    /// Set the DA validator pair of the chain, this is the L2 part of the pair
+    #[clap(long, requires = "l1_da_validator")]
+    pub l2_da_validator: Option<Address>,
+
+    /// Set the pubdata pricing mode of the chain
+    #[clap(long, value_enum)]
+    pub pubdata_pricing_mode: Option<L1BatchCommitmentModeInternal>,
+}
+
+pub async fn run(args: BuildAdminBatchArgs) -> anyhow::Result<()> {
+    anyhow::ensure!(
+        args.transaction_filterer.is_some()
+            || args.l1_da_validator.is_some()
+            || args.pubdata_pricing_mode.is_some(),
+        MSG_ADMIN_BATCH_EMPTY_ERR
+    );
+
+    let l1_provider = get_ethers_provider(&args.l1_rpc_url)?;
+    let bridgehub = BridgehubAbi::new(args.bridgehub_address, l1_provider.clone());
+    let diamond_proxy = bridgehub.get_zk_chain(args.chain_id.into()).await?;
+    anyhow::ensure!(
+        diamond_proxy != Address::zero(),
+        MSG_ADMIN_BATCH_NOT_REGISTERED_ERR
+    );
+    let l1_chain_id = l1_provider.get_chainid().await?;
+    let settlement_layer = bridgehub.settlement_layer(args.chain_id.into()).await?;
+    anyhow::ensure!(
+        settlement_layer == l1_chain_id,
+        MSG_ADMIN_BATCH_CHAIN_NOT_ON_L1_ERR
+    );
+    let admin_address = ZkChainAbi::new(diamond_proxy, l1_provider)
+        .get_admin()
+        .await?;
+
+    let mut builder = AdminCallBuilder::new(vec![]);
+    if let Some(transaction_filterer) = args.transaction_filterer {
+        builder.append_set_transaction_filterer(diamond_proxy, transaction_filterer)?;
+    }
+    if let (Some(l1_da_validator), Some(l2_da_validator)) =
+        (args.l1_da_validator, args.l2_da_validator)
+    {
+        builder.append_set_da_validator_pair(diamond_proxy, l1_da_validator, l2_da_validator)?;
+    }
+    if let Some(mode) = args.pubdata_pricing_mode {
+        builder.append_set_pubdata_pricing_mode(diamond_proxy, mode.into())?;
+    }
+
+    display_admin_script_output(AdminScriptOutput {
+        admin_address,
+        calls: builder.into_calls(),
+    });
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::Context;
+    use ethers::abi::Token;
+    use zkstack_cli_types::L1BatchCommitmentMode;
+    use zksync_types::U256;
+
+    use super::*;
+    use crate::abi::{CHAINADMINOWNABLEABI_ABI, ZKCHAINABI_ABI};
+
+    #[test]
+    fn test_batched_calls_are_encoded_into_single_multicall() -> anyhow::Result<()> {
+        let diamond_proxy = Address::repeat_byte(0x11);
+        let transaction_filterer = Address::repeat_byte(0x22);
+        let l1_da_validator = Address::repeat_byte(0x33);
+        let l2_da_validator = Address::repeat_byte(0x44);
+        let mut builder = AdminCallBuilder::new(vec![]);
+        builder.append_set_transaction_filterer(diamond_proxy, transaction_filterer)?;
+        builder.append_set_da_validator_pair(diamond_proxy, l1_da_validator, l2_da_validator)?;
+        builder.append_set_pubdata_pricing_mode(diamond_proxy, L1BatchCommitmentMode::Validium)?;
+
+        let (calldata, value) = builder.compile_full_calldata();
+        assert_eq!(value, U256::zero());
+
+        let multicall = CHAINADMINOWNABLEABI_ABI.function("multicall")?;
+        assert_eq!(calldata[..4], multicall.short_signature());
+        let mut inputs = multicall.decode_input(&calldata[4..])?;
+        assert_eq!(inputs.pop(), Some(Token::Bool(true)));
+        let inner_calls = inputs
+            .pop()
+            .and_then(Token::into_array)
+            .context("multicall calls are not an array")?;
+
+        let expected = [
+            (
+                "setTransactionFilterer",
+                vec![Token::Address(transaction_filterer)],
+            ),
+            (
+                "setDAValidatorPair",
+                vec![
+                    Token::Address(l1_da_validator),
+                    Token::Address(l2_da_validator),
+                ],
+            ),
+            ("setPubdataPricingMode", vec![Token::Uint(1.into())]),
+        ];
+        assert_eq!(inner_calls.len(), expected.len());
+        for (call, (function_name, expected_args)) in inner_calls.into_iter().zip(expected) {
+            let fields = call.into_tuple().context("call is not a tuple")?;
+            assert_eq!(fields[0], Token::Address(diamond_proxy));
+            assert_eq!(fields[1], Token::Uint(U256::zero()));
+
+            let data = fields[2]
+                .clone()
+                .into_bytes()
+                .context("call data is not bytes")?;
+            let function = ZKCHAINABI_ABI.function(function_name)?;
+            assert_eq!(data[..4], function.short_signature(), "{function_name}");
+            assert_eq!(
+                function.decode_input(&data[4..])?,
+                expected_args,
+                "{function_name}"
+            );
+        }
+        Ok(())
+    }
+}
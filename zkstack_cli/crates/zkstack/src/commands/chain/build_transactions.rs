@@ -1,24 +1,30 @@
 use anyhow::Context;
-use ethers::utils::hex::ToHexExt;
+use ethers::{
+    types::{Address, Bytes, U256},
+    utils::hex::ToHexExt,
+};
+use serde::{Deserialize, Serialize};
 use xshell::Shell;
 use zkstack_cli_common::{logger, spinner::Spinner};
 use zkstack_cli_config::{copy_configs, traits::SaveConfigWithBasePath, ZkStackConfig};
 
 use crate::{
     commands::chain::{
-        args::build_transactions::BuildTransactionsArgs, register_chain::register_chain,
+        args::build_transactions::{BuildTransactionsArgs, TransactionsOutputFormat},
+        register_chain::register_chain,
     },
     messages::{
-        MSG_BUILDING_CHAIN_REGISTRATION_TXNS_SPINNER, MSG_CHAIN_NOT_FOUND_ERR,
-        MSG_CHAIN_TRANSACTIONS_BUILT, MSG_CHAIN_TXN_MISSING_CONTRACT_CONFIG,
-        MSG_CHAIN_TXN_OUT_PATH_INVALID_ERR, MSG_PREPARING_CONFIG_SPINNER, MSG_SELECTED_CONFIG,
-        MSG_WRITING_OUTPUT_FILES_SPINNER,
+        msg_safe_batch_skipped_contract_creations, MSG_BUILDING_CHAIN_REGISTRATION_TXNS_SPINNER,
+        MSG_CHAIN_NOT_FOUND_ERR, MSG_CHAIN_TRANSACTIONS_BUILT,
+        MSG_CHAIN_TXN_MISSING_CONTRACT_CONFIG, MSG_CHAIN_TXN_OUT_PATH_INVALID_ERR,
+        MSG_PREPARING_CONFIG_SPINNER, MSG_SELECTED_CONFIG, MSG_WRITING_OUTPUT_FILES_SPINNER,
     },
 };
 
 pub const REGISTER_CHAIN_TXNS_FILE_SRC: &str =
     "l1-contracts/broadcast/RegisterZKChain.s.sol/9/dry-run/run-latest.json";
 pub const REGISTER_CHAIN_TXNS_FILE_DST: &str = "register-zk-chain-txns.json";
+pub const REGISTER_CHAIN_SAFE_BATCH_FILE_DST: &str = "register-zk-chain-safe-batch.json";
 
 const SCRIPT_CONFIG_FILE_SRC: &str = "l1-contracts/script-config/register-zk-chain.toml";
 const SCRIPT_CONFIG_FILE_DST: &str = "register-zk-chain.toml";
@@ -75,12 +81,26 @@ pub(crate) async fn run(args: BuildTransactionsArgs, shell: &Shell) -> anyhow::R
         .create_dir(&args.out)
         .context(MSG_CHAIN_TXN_OUT_PATH_INVALID_ERR)?;
 
-    shell.copy_file(
-        config
-            .contracts_path_for_ctm(vm_option)
-            .join(REGISTER_CHAIN_TXNS_FILE_SRC),
-        args.out.join(REGISTER_CHAIN_TXNS_FILE_DST),
-    )?;
+    let txns_file_src = config
+        .contracts_path_for_ctm(vm_option)
+        .join(REGISTER_CHAIN_TXNS_FILE_SRC);
+    match args.output_format {
+        TransactionsOutputFormat::Forge => {
+            shell.copy_file(txns_file_src, args.out.join(REGISTER_CHAIN_TXNS_FILE_DST))?;
+        }
+        TransactionsOutputFormat::SafeJson => {
+            let broadcast: ForgeBroadcast = serde_json::from_str(&shell.read_file(txns_file_src)?)?;
+            let batch = SafeBatch::new(
+                broadcast,
+                config.l1_network.chain_id(),
+                chrono::Utc::now().timestamp_millis(),
+            );
+            shell.write_file(
+                args.out.join(REGISTER_CHAIN_SAFE_BATCH_FILE_DST),
+                serde_json::to_string_pretty(&batch)?,
+            )?;
+        }
+    }
 
     shell.copy_file(
         config
@@ -93,3 +113,187 @@ pub(crate) async fn run(args: BuildTransactionsArgs, shell: &Shell) -> anyhow::R
     logger::success(MSG_CHAIN_TRANSACTIONS_BUILT);
     Ok(())
 }
+
+/// Subset of the forge broadcast file that is needed to rebuild the transactions.
+#[derive(Debug, Deserialize)]
+struct ForgeBroadcast {
+    transactions: Vec<ForgeBroadcastTransaction>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct ForgeBroadcastTransaction {
+    #[serde(default)]
+    contract_name: Option<String>,
+    transaction: ForgeTransactionRequest,
+}
+
+#[derive(Debug, Deserialize)]
+struct ForgeTransactionRequest {
+    to: Option<Address>,
+    #[serde(default)]
+    value: U256,
+    // Older forge versions name the field `data`.
+    #[serde(alias = "data")]
+    input: Bytes,
+}
+
+/// Transaction batch in the format of the Safe Transaction Builder.
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SafeBatch {
+    version: &'static str,
+    chain_id: String,
+    created_at: i64,
+    meta: SafeBatchMeta,
+    transactions: Vec<SafeTransaction>,
+}
+
+#[derive(Debug, Serialize)]
+struct SafeBatchMeta {
+    name: &'static str,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SafeTransaction {
+    to: Address,
+    value: String,
+    data: Bytes,
+    contract_method: Option<()>,
+    contract_inputs_values: Option<()>,
+}
+
+impl SafeBatch {
+    /// Builds the batch from the calls in the broadcast.
+    ///
+    /// A Safe can't deploy contracts from a batch, so contract creations (`to: null`)
+    /// are left out with a warning.
+    fn new(broadcast: ForgeBroadcast, chain_id: u64, created_at: i64) -> Self {
+        let mut skipped_contracts = vec![];
+        let transactions = broadcast
+            .transactions
+            .into_iter()
+            .filter_map(|tx| {
+                let Some(to) = tx.transaction.to else {
+                    skipped_contracts.push(tx.contract_name.unwrap_or_else(|| "unknown".into()));
+                    return None;
+                };
+                Some(SafeTransaction {
+                    to,
+                    value: tx.transaction.value.to_string(),
+                    data: tx.transaction.input,
+                    contract_method: None,
+                    contract_inputs_values: None,
+                })
+            })
+            .collect();
+        if !skipped_contracts.is_empty() {
+            logger::warn(msg_safe_batch_skipped_contract_creations(
+                &skipped_contracts,
+            ));
+        }
+        Self {
+            version: "1.0",
+            chain_id: chain_id.to_string(),
+            created_at,
+            meta: SafeBatchMeta {
+                name: "zkstack-chain-init",
+            },
+            transactions,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_safe_batch_from_forge_broadcast() {
+        let broadcast: ForgeBroadcast = serde_json::from_str(
+            r#"{
+                "transactions": [
+                    {
+                        "hash": null,
+                        "transactionType": "CALL",
+                        "transaction": {
+                            "from": "0x1111111111111111111111111111111111111111",
+                            "to": "0x2222222222222222222222222222222222222222",
+                            "gas": "0x1e8480",
+                            "value": "0xde0b6b3a7640000",
+                            "input": "0xdeadbeef",
+                            "nonce": "0x1"
+                        }
+                    },
+                    {
+                        "transaction": {
+                            "to": "0x3333333333333333333333333333333333333333",
+                            "data": "0x01"
+                        }
+                    }
+                ],
+                "chain": 9
+            }"#,
+        )
+        .unwrap();
+
+        let batch = serde_json::to_value(SafeBatch::new(broadcast, 11_155_111, 42)).unwrap();
+        assert_eq!(
+            batch,
+            serde_json::json!({
+                "version": "1.0",
+                "chainId": "11155111",
+                "createdAt": 42,
+                "meta": { "name": "zkstack-chain-init" },
+                "transactions": [
+                    {
+                        "to": "0x2222222222222222222222222222222222222222",
+                        "value": "1000000000000000000",
+                        "data": "0xdeadbeef",
+                        "contractMethod": null,
+                        "contractInputsValues": null
+                    },
+                    {
+                        "to": "0x3333333333333333333333333333333333333333",
+                        "value": "0",
+                        "data": "0x01",
+                        "contractMethod": null,
+                        "contractInputsValues": null
+                    }
+                ]
+            })
+        );
+    }
+
+    #[test]
+    fn test_safe_batch_skips_contract_creations() {
+        let broadcast: ForgeBroadcast =
+            serde_json::from_str(include_str!("testdata/register_zk_chain_broadcast.json"))
+                .unwrap();
+        assert_eq!(broadcast.transactions.len(), 4);
+
+        let batch = SafeBatch::new(broadcast, 9, 0);
+        assert_eq!(
+            batch
+                .transactions
+                .iter()
+                .map(|tx| (tx.to, tx.data.len()))
+                .collect::<Vec<_>>(),
+            [
+                (
+                    "0x9fe46736679d2d9a65f0992f2272de9f3c7fa6e0"
+                        .parse()
+                        .unwrap(),
+                    36
+                ),
+                (
+                    "0x5fbdb2315678afecb367f032d93f642f64180aa3"
+                        .parse()
+                        .unwrap(),
+                    36
+                ),
+            ]
+        );
+    }
+}
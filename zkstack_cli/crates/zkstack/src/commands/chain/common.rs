@@ -1,12 +1,78 @@
-use zkstack_cli_common::spinner::Spinner;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use anyhow::Context;
+use ethers::{providers::Middleware, types::BlockNumber};
+use zkstack_cli_common::{ethereum::get_ethers_provider, spinner::Spinner};
 use zkstack_cli_config::{ChainConfig, EcosystemConfig};
 use zkstack_cli_types::{BaseToken, L1Network, WalletCreation};
 
 use crate::{
     consts::AMOUNT_FOR_DISTRIBUTION_TO_WALLETS,
-    messages::{MSG_DISTRIBUTING_ETH_SPINNER, MSG_MINT_BASE_TOKEN_SPINNER},
+    messages::{
+        msg_l1_block_too_old, msg_l1_chain_id_mismatch, MSG_CHECKING_L1_BLOCK_AGE_SPINNER,
+        MSG_CHECKING_L1_CHAIN_ID_SPINNER, MSG_DISTRIBUTING_ETH_SPINNER,
+        MSG_FETCHING_L1_CHAIN_ID_ERR, MSG_FETCHING_L1_LATEST_BLOCK_ERR,
+        MSG_MINT_BASE_TOKEN_SPINNER,
+    },
 };
 
+/// Checks that the L1 RPC serves the network the chain is registered on.
+pub async fn check_l1_chain_id(l1_network: L1Network, l1_rpc_url: &str) -> anyhow::Result<()> {
+    let spinner = Spinner::new(MSG_CHECKING_L1_CHAIN_ID_SPINNER);
+    let chain_id = get_ethers_provider(l1_rpc_url)?
+        .get_chainid()
+        .await
+        .context(MSG_FETCHING_L1_CHAIN_ID_ERR)?;
+    spinner.finish();
+    ensure_l1_chain_id(l1_network, chain_id.as_u64())
+}
+
+fn ensure_l1_chain_id(l1_network: L1Network, actual_chain_id: u64) -> anyhow::Result<()> {
+    let expected_chain_id = l1_network.chain_id();
+    anyhow::ensure!(
+        actual_chain_id == expected_chain_id,
+        msg_l1_chain_id_mismatch(l1_network, expected_chain_id, actual_chain_id)
+    );
+    Ok(())
+}
+
+/// Number of L1 block times the latest block may lag behind the wall clock by default.
+const MAX_L1_BLOCK_AGE_IN_BLOCK_TIMES: u32 = 5;
+/// Lower bound of the default, so that clock skew and dev nodes with sub-second
+/// block times do not fail the check.
+const MIN_DEFAULT_MAX_L1_BLOCK_AGE: Duration = Duration::from_secs(30);
+
+pub fn default_max_l1_block_age(l1_network: L1Network) -> Duration {
+    (l1_network.block_time() * MAX_L1_BLOCK_AGE_IN_BLOCK_TIMES).max(MIN_DEFAULT_MAX_L1_BLOCK_AGE)
+}
+
+/// Checks that the L1 RPC serves recent blocks, i.e. that L1 is not stalled and the node is synced.
+pub async fn check_l1_block_age(l1_rpc_url: &str, max_block_age: Duration) -> anyhow::Result<()> {
+    let spinner = Spinner::new(MSG_CHECKING_L1_BLOCK_AGE_SPINNER);
+    let block = get_ethers_provider(l1_rpc_url)?
+        .get_block(BlockNumber::Latest)
+        .await
+        .context(MSG_FETCHING_L1_LATEST_BLOCK_ERR)?
+        .context(MSG_FETCHING_L1_LATEST_BLOCK_ERR)?;
+    spinner.finish();
+    let now = SystemTime::now().duration_since(UNIX_EPOCH)?.as_secs();
+    ensure_l1_block_age(block.timestamp.as_u64(), now, max_block_age)
+}
+
+fn ensure_l1_block_age(
+    block_timestamp: u64,
+    now: u64,
+    max_block_age: Duration,
+) -> anyhow::Result<()> {
+    // Blocks slightly in the future are possible with clock skew and are not a sign of a stall.
+    let block_age = now.saturating_sub(block_timestamp);
+    anyhow::ensure!(
+        block_age <= max_block_age.as_secs(),
+        msg_l1_block_too_old(block_age, max_block_age)
+    );
+    Ok(())
+}
+
 // Distribute eth to the chain wallets for localhost environment
 pub async fn distribute_eth(
     ecosystem_config: &EcosystemConfig,
@@ -78,3 +144,37 @@ pub async fn mint_base_token(
     }
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ensure_l1_chain_id() {
+        ensure_l1_chain_id(L1Network::Sepolia, 11_155_111).unwrap();
+        ensure_l1_chain_id(L1Network::Localhost, 9).unwrap();
+
+        let err = ensure_l1_chain_id(L1Network::Sepolia, 1)
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("11155111"), "{err}");
+        assert!(err.contains("Sepolia"), "{err}");
+    }
+
+    #[test]
+    fn test_ensure_l1_block_age() {
+        let max_block_age = default_max_l1_block_age(L1Network::Mainnet);
+        assert_eq!(max_block_age, Duration::from_secs(60));
+        assert_eq!(
+            default_max_l1_block_age(L1Network::Localhost),
+            MIN_DEFAULT_MAX_L1_BLOCK_AGE
+        );
+
+        ensure_l1_block_age(1_000, 1_060, max_block_age).unwrap();
+        ensure_l1_block_age(1_100, 1_060, max_block_age).unwrap();
+        let err = ensure_l1_block_age(1_000, 1_061, max_block_age)
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("61s"), "{err}");
+    }
+}
@@ -0,0 +1,166 @@
+use std::path::PathBuf;
+
+use anyhow::Context;
+use clap::Parser;
+use serde::{Deserialize, Serialize};
+use xshell::Shell;
+use zkstack_cli_common::{forge::ForgeScriptArgs, logger};
+use zkstack_cli_config::{
+    ChainConfig, ConsensusGenesisSpecs, RawConsensusKeys, Weighted, ZkStackConfig,
+};
+use zksync_consensus_crypto::{Text, TextFmt};
+use zksync_consensus_roles::validator;
+
+use crate::{
+    commands::{
+        chain::deploy_l2_contracts::{self, Deploy2ContractsOption},
+        consensus::RegistryKeyRotation,
+    },
+    messages::{
+        msg_consensus_keys_rotated, msg_rotated_key_not_published, msg_rotated_key_not_registered,
+        msg_secrets_backed_up, msg_secrets_backup_exists, MSG_CHAIN_NOT_INITIALIZED,
+        MSG_ROTATE_CONSENSUS_KEY_HELP, MSG_VALIDATOR_KEY_NOT_IN_GENESIS_SPEC_ERR,
+    },
+};
+
+#[derive(Debug, Serialize, Deserialize, Parser)]
+pub struct DeployConsensusRegistryArgs {
+    /// All ethereum environment related arguments
+    #[clap(flatten)]
+    #[serde(flatten)]
+    pub forge_args: ForgeScriptArgs,
+    #[clap(long, help = MSG_ROTATE_CONSENSUS_KEY_HELP)]
+    pub rotate_key: bool,
+}
+
+pub async fn run(args: DeployConsensusRegistryArgs, shell: &Shell) -> anyhow::Result<()> {
+    if args.rotate_key {
+        // Rotation works on the registry that is already deployed: redeploying it would
+        // drop the registered validators and overwrite its address in the contracts config.
+        let chain_config =
+            ZkStackConfig::current_chain(shell).context(MSG_CHAIN_NOT_INITIALIZED)?;
+        return rotate_validator_key(shell, &chain_config).await;
+    }
+
+    deploy_l2_contracts::run(
+        args.forge_args,
+        shell,
+        Deploy2ContractsOption::ConsensusRegistry,
+    )
+    .await
+}
+
+/// Replaces the main node validator key with a freshly generated one,
+/// both locally and in the deployed consensus registry.
+///
+/// The node key is kept, since external nodes reference it in their gossip configs.
+async fn rotate_validator_key(shell: &Shell, chain_config: &ChainConfig) -> anyhow::Result<()> {
+    let secrets = chain_config.get_secrets_config().await?;
+    let old_secret: validator::SecretKey = Text::new(&secrets.raw_consensus_validator_key()?)
+        .decode()
+        .context("validator_key")?;
+    let old_public = old_secret.public().encode();
+    let registry_rotation = RegistryKeyRotation::prepare(shell, &old_secret.public()).await?;
+
+    let general_config = chain_config.get_general_config().await?;
+    let mut validators = general_config.consensus_validators()?;
+    let mut leader = general_config.consensus_leader()?;
+    let keys = RawConsensusKeys::generate();
+    let new_secret: validator::SecretKey = Text::new(&keys.validator_secret)
+        .decode()
+        .context("validator_secret")?;
+    replace_validator_key(
+        &mut validators,
+        &mut leader,
+        &old_public,
+        &keys.validator_public,
+    )?;
+
+    // Secrets are written first: if updating the genesis spec fails afterwards,
+    // the new key can still be published by hand, while a lost secret can't be recovered.
+    let backup_path = backup_secrets(shell, chain_config)?;
+    logger::info(msg_secrets_backed_up(&backup_path));
+    let mut secrets = secrets.patched();
+    secrets.set_consensus_validator_key(&keys.validator_secret)?;
+    secrets.save().await?;
+
+    let mut general_config = general_config.patched();
+    general_config.set_consensus_specs(ConsensusGenesisSpecs {
+        chain_id: chain_config.chain_id,
+        validators,
+        leader,
+    })?;
+    general_config
+        .save()
+        .await
+        .with_context(|| msg_rotated_key_not_published(&keys.validator_public, &backup_path))?;
+
+    registry_rotation
+        .commit(&new_secret)
+        .await
+        .with_context(|| msg_rotated_key_not_registered(&old_public, &keys.validator_public))?;
+
+    logger::success(msg_consensus_keys_rotated(&keys.validator_public));
+    Ok(())
+}
+
+/// Copies the secrets config to a timestamped file next to it, so that earlier keys survive repeated rotations.
+fn backup_secrets(shell: &Shell, chain_config: &ChainConfig) -> anyhow::Result<PathBuf> {
+    let secrets_path = chain_config.path_to_secrets_config();
+    let timestamp = chrono::Utc::now().format("%Y%m%dT%H%M%SZ");
+    let backup_path = secrets_path.with_extension(format!("yaml.{timestamp}.bak"));
+    anyhow::ensure!(
+        !shell.path_exists(&backup_path),
+        msg_secrets_backup_exists(&backup_path)
+    );
+    shell.copy_file(&secrets_path, &backup_path)?;
+    Ok(backup_path)
+}
+
+/// Replaces `old_key` in the validator set, keeping its weight and leadership.
+/// Other validators are left untouched.
+fn replace_validator_key(
+    validators: &mut [Weighted],
+    leader: &mut String,
+    old_key: &str,
+    new_key: &str,
+) -> anyhow::Result<()> {
+    let validator = validators
+        .iter_mut()
+        .find(|validator| validator.key == old_key)
+        .context(MSG_VALIDATOR_KEY_NOT_IN_GENESIS_SPEC_ERR)?;
+    validator.key = new_key.to_owned();
+    if leader == old_key {
+        *leader = new_key.to_owned();
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_replace_validator_key() {
+        let mut validators = vec![
+            Weighted::new("old".into(), 2),
+            Weighted::new("other".into(), 1),
+        ];
+        let mut leader = "old".to_owned();
+        replace_validator_key(&mut validators, &mut leader, "old", "new").unwrap();
+        assert_eq!(leader, "new");
+        assert_eq!(
+            validators
+                .iter()
+                .map(|validator| (validator.key.as_str(), validator.weight))
+                .collect::<Vec<_>>(),
+            [("new", 2), ("other", 1)]
+        );
+
+        let mut leader = "other".to_owned();
+        replace_validator_key(&mut validators, &mut leader, "new", "newer").unwrap();
+        assert_eq!(leader, "other");
+
+        assert!(replace_validator_key(&mut validators, &mut leader, "missing", "new").is_err());
+    }
+}
@@ -19,7 +19,7 @@ pub(crate) mod migrate_to_gateway_calldata;
 mod notify_server_calldata;
 
 #[derive(Subcommand, Debug)]
-pub enum GatewayComamnds {
+pub enum GatewayCommands {
     GrantGatewayTransactionFiltererWhitelistCalldata(GrantGatewayWhitelistCalldataArgs),
     NotifyAboutToGatewayUpdateCalldata(notify_server_calldata::NotifyServerCalldataArgs),
     NotifyAboutFromGatewayUpdateCalldata(notify_server_calldata::NotifyServerCalldataArgs),
@@ -44,38 +44,59 @@ pub enum GatewayComamnds {
     NotifyAboutFromGatewayUpdate(ForgeScriptArgs),
 }
 
-pub async fn run(shell: &Shell, args: GatewayComamnds) -> anyhow::Result<()> {
+/// Old misspelled name of [`GatewayCommands`], kept for source compatibility.
+#[deprecated(note = "use `GatewayCommands` instead")]
+#[allow(dead_code)]
+pub type GatewayComamnds = GatewayCommands;
+
+pub async fn run(shell: &Shell, args: GatewayCommands) -> anyhow::Result<()> {
     match args {
-        GatewayComamnds::GrantGatewayTransactionFiltererWhitelistCalldata(args) => {
+        GatewayCommands::GrantGatewayTransactionFiltererWhitelistCalldata(args) => {
             grant_gateway_whitelist::run(shell, args).await
         }
-        GatewayComamnds::NotifyAboutToGatewayUpdateCalldata(args) => {
+        GatewayCommands::NotifyAboutToGatewayUpdateCalldata(args) => {
             notify_server_calldata::run(shell, args, MigrationDirection::ToGateway).await
         }
-        GatewayComamnds::MigrateToGatewayCalldata(args) => {
+        GatewayCommands::MigrateToGatewayCalldata(args) => {
             migrate_to_gateway_calldata::run(shell, args).await
         }
-        GatewayComamnds::MigrateFromGatewayCalldata(args) => {
+        GatewayCommands::MigrateFromGatewayCalldata(args) => {
             migrate_from_gateway_calldata::run(shell, args).await
         }
-        GatewayComamnds::FinalizeChainMigrationFromGateway(args) => {
+        GatewayCommands::FinalizeChainMigrationFromGateway(args) => {
             finalize_chain_migration_from_gw::run(shell, args).await
         }
-        GatewayComamnds::NotifyAboutFromGatewayUpdateCalldata(args) => {
+        GatewayCommands::NotifyAboutFromGatewayUpdateCalldata(args) => {
             notify_server_calldata::run(shell, args, MigrationDirection::FromGateway).await
         }
-        GatewayComamnds::CreateTxFilterer(args) => create_tx_filterer::run(args, shell).await,
-        GatewayComamnds::ConvertToGateway(args) => convert_to_gateway::run(args, shell).await,
-        GatewayComamnds::MigrateToGateway(args) => migrate_to_gateway::run(args, shell).await,
-        GatewayComamnds::FinalizeChainMigrationToGateway(args) => {
+        GatewayCommands::CreateTxFilterer(args) => create_tx_filterer::run(args, shell).await,
+        GatewayCommands::ConvertToGateway(args) => convert_to_gateway::run(args, shell).await,
+        GatewayCommands::MigrateToGateway(args) => migrate_to_gateway::run(args, shell).await,
+        GatewayCommands::FinalizeChainMigrationToGateway(args) => {
             finalize_chain_migration_to_gateway::run(args, shell).await
         }
-        GatewayComamnds::MigrateFromGateway(args) => migrate_from_gateway::run(args, shell).await,
-        GatewayComamnds::NotifyAboutToGatewayUpdate(args) => {
+        GatewayCommands::MigrateFromGateway(args) => migrate_from_gateway::run(args, shell).await,
+        GatewayCommands::NotifyAboutToGatewayUpdate(args) => {
             gateway_common::notify_server(args, shell, MigrationDirection::ToGateway).await
         }
-        GatewayComamnds::NotifyAboutFromGatewayUpdate(args) => {
+        GatewayCommands::NotifyAboutFromGatewayUpdate(args) => {
             gateway_common::notify_server(args, shell, MigrationDirection::FromGateway).await
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::any::TypeId;
+
+    use super::GatewayCommands;
+
+    #[test]
+    #[allow(deprecated)]
+    fn test_deprecated_alias_is_gateway_commands() {
+        assert_eq!(
+            TypeId::of::<super::GatewayComamnds>(),
+            TypeId::of::<GatewayCommands>()
+        );
+    }
+}
@@ -6,6 +6,7 @@ use zkstack_cli_config::{ChainConfig, ZkStackConfig, ZkStackConfigTrait};
 use crate::{
     commands::chain::{
         args::genesis::{GenesisArgs, GenesisArgsFinal},
+        common::check_l1_chain_id,
         genesis::{database::initialize_server_database, server::run_server_genesis},
     },
     messages::{
@@ -46,7 +47,7 @@ pub(crate) async fn run(args: GenesisCommand, shell: &Shell) -> anyhow::Result<(
 
 pub async fn run_genesis(args: GenesisArgs, shell: &Shell) -> anyhow::Result<()> {
     let chain_config = ZkStackConfig::current_chain(shell)?;
-    let args = args.fill_values_with_prompt(&chain_config);
+    let args = args.fill_values_with_prompt(&chain_config).await;
 
     genesis(&args, shell, &chain_config).await?;
     logger::outro(MSG_GENESIS_COMPLETED);
@@ -59,6 +60,11 @@ pub async fn genesis(
     shell: &Shell,
     config: &ChainConfig,
 ) -> anyhow::Result<()> {
+    if !args.skip_l1_check {
+        let l1_rpc_url = config.get_secrets_config().await?.l1_rpc_url()?;
+        check_l1_chain_id(config.l1_network, &l1_rpc_url).await?;
+    }
+
     let override_validium_config = true;
     database::update_configs(args, shell, config, override_validium_config).await?;
 
@@ -14,6 +14,7 @@ use crate::{
                 da_configs::ValidiumType,
             },
             genesis,
+            init::validate::{report_validation_issues, ChainConfigValidator},
         },
         portal::update_portal_config,
     },
@@ -23,7 +24,7 @@ use crate::{
 
 pub async fn run(args: InitConfigsArgs, shell: &Shell) -> anyhow::Result<()> {
     let chain_config = ZkStackConfig::current_chain(shell)?;
-    let args = args.fill_values_with_prompt(&chain_config);
+    let args = args.fill_values_with_prompt(&chain_config).await;
 
     init_configs(&args, shell, &chain_config).await?;
     logger::outro(MSG_CHAIN_CONFIGS_INITIALIZED);
@@ -36,6 +37,11 @@ pub async fn init_configs(
     shell: &Shell,
     chain_config: &ChainConfig,
 ) -> anyhow::Result<()> {
+    let issues = ChainConfigValidator::new(shell)
+        .validate(chain_config, init_args)
+        .await?;
+    report_validation_issues(&issues)?;
+
     // Port scanner should run before copying configs to avoid marking initial ports as assigned
     let mut ecosystem_ports = EcosystemPortsScanner::scan(shell, Some(&chain_config.name))?;
     copy_configs(
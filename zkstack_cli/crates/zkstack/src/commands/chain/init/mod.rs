@@ -6,7 +6,7 @@ use zkstack_cli_config::{
     traits::SaveConfigWithBasePath, ChainConfig, ContractsConfig, EcosystemConfig, ZkStackConfig,
     ZkStackConfigTrait,
 };
-use zkstack_cli_types::{BaseToken, L1BatchCommitmentMode};
+use zkstack_cli_types::{BaseToken, L1BatchCommitmentMode, L1Network};
 use zksync_basic_types::Address;
 
 use crate::{
@@ -26,15 +26,17 @@ use crate::{
     },
     enable_evm_emulator::enable_evm_emulator,
     messages::{
-        msg_initializing_chain, MSG_ACCEPTING_ADMIN_SPINNER, MSG_CHAIN_INITIALIZED,
-        MSG_CHAIN_NOT_FOUND_ERR, MSG_DA_PAIR_REGISTRATION_SPINNER, MSG_DEPLOYING_PAYMASTER,
-        MSG_GENESIS_DATABASE_ERR, MSG_REGISTERING_CHAIN_SPINNER, MSG_SELECTED_CONFIG,
-        MSG_UPDATING_TOKEN_MULTIPLIER_SETTER_SPINNER, MSG_WALLET_TOKEN_MULTIPLIER_SETTER_NOT_FOUND,
+        msg_avail_l1_da_validator_not_set, msg_initializing_chain, MSG_ACCEPTING_ADMIN_SPINNER,
+        MSG_CHAIN_INITIALIZED, MSG_CHAIN_NOT_FOUND_ERR, MSG_DA_PAIR_REGISTRATION_SPINNER,
+        MSG_DEPLOYING_PAYMASTER, MSG_GENESIS_DATABASE_ERR, MSG_REGISTERING_CHAIN_SPINNER,
+        MSG_SELECTED_CONFIG, MSG_UPDATING_TOKEN_MULTIPLIER_SETTER_SPINNER,
+        MSG_WALLET_TOKEN_MULTIPLIER_SETTER_NOT_FOUND,
     },
 };
 
 // Init subcommands
 pub mod configs;
+pub mod validate;
 
 #[derive(Subcommand, Debug, Clone)]
 pub enum ChainInitSubcommands {
@@ -64,7 +66,7 @@ async fn run_init(args: InitArgs, shell: &Shell) -> anyhow::Result<()> {
         .load_current_chain()
         .context(MSG_CHAIN_NOT_FOUND_ERR)?;
 
-    let args = args.fill_values_with_prompt(&chain_config);
+    let args = args.fill_values_with_prompt(&chain_config).await;
 
     logger::note(MSG_SELECTED_CONFIG, logger::object_to_string(&chain_config));
     logger::info(msg_initializing_chain(""));
@@ -284,7 +286,10 @@ pub(crate) async fn get_l1_da_validator(chain_config: &ChainConfig) -> anyhow::R
         L1BatchCommitmentMode::Validium => {
             let general_config = chain_config.get_general_config().await?;
             match general_config.da_client_type().as_deref() {
-                Some("Avail") => contracts_config.l1.avail_l1_da_validator_addr,
+                Some("Avail") => Some(avail_l1_da_validator(
+                    contracts_config.l1.avail_l1_da_validator_addr,
+                    chain_config.l1_network,
+                )?),
                 Some("NoDA") | None => contracts_config.l1.no_da_validium_l1_validator_addr,
                 Some("Eigen") => contracts_config.l1.no_da_validium_l1_validator_addr, // TODO: change for eigenda l1 validator for M1
                 Some(unsupported) => {
@@ -297,3 +302,57 @@ pub(crate) async fn get_l1_da_validator(chain_config: &ChainConfig) -> anyhow::R
 
     Ok(l1_da_validator_contract)
 }
+
+/// Returns the Avail L1 DA validator deployed with the ecosystem. It is missing if the ecosystem
+/// was deployed without an override and the L1 network has no builtin address.
+fn avail_l1_da_validator(
+    deployed: Option<Address>,
+    l1_network: L1Network,
+) -> anyhow::Result<Address> {
+    deployed.with_context(|| msg_avail_l1_da_validator_not_set(l1_network))
+}
+
+#[cfg(test)]
+mod tests {
+    use zkstack_cli_config::forge_interface::deploy_ecosystem::input::InitialDeploymentConfig;
+
+    use super::*;
+
+    #[test]
+    fn test_avail_l1_da_validator_override() {
+        let override_addr = Address::repeat_byte(0x11);
+        let config = InitialDeploymentConfig {
+            avail_l1_da_validator_addr: Some(override_addr),
+            ..Default::default()
+        };
+        for l1_network in [L1Network::Sepolia, L1Network::Mainnet] {
+            let deployed = config.avail_l1_da_validator_addr_for(l1_network);
+            assert_eq!(
+                avail_l1_da_validator(deployed, l1_network).unwrap(),
+                override_addr
+            );
+        }
+    }
+
+    #[test]
+    fn test_avail_l1_da_validator_builtin() {
+        let config = InitialDeploymentConfig::default();
+        let deployed = config.avail_l1_da_validator_addr_for(L1Network::Sepolia);
+        assert_eq!(
+            avail_l1_da_validator(deployed, L1Network::Sepolia).unwrap(),
+            L1Network::Sepolia.avail_l1_da_validator_addr().unwrap()
+        );
+    }
+
+    #[test]
+    fn test_avail_l1_da_validator_not_set() {
+        let config = InitialDeploymentConfig::default();
+        let deployed = config.avail_l1_da_validator_addr_for(L1Network::Mainnet);
+        assert_eq!(deployed, None);
+        let err = avail_l1_da_validator(deployed, L1Network::Mainnet).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            msg_avail_l1_da_validator_not_set(L1Network::Mainnet)
+        );
+    }
+}
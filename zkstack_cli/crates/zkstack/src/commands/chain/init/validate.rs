@@ -0,0 +1,278 @@
+use anyhow::Context;
+use ethers::providers::Middleware;
+use xshell::Shell;
+use zkstack_cli_common::{ethereum::get_ethers_provider, logger};
+use zkstack_cli_config::{ChainConfig, GeneralConfig, ZkStackConfigTrait, GENERAL_FILE};
+use zkstack_cli_types::{BaseToken, L1BatchCommitmentMode, ETH_ADDRESS};
+
+use crate::{
+    commands::chain::{
+        args::init::configs::InitConfigsArgsFinal,
+        common::{check_l1_block_age, check_l1_chain_id},
+    },
+    messages::{
+        msg_base_token_check_failed, msg_base_token_not_deployed, msg_port_conflict,
+        msg_pubdata_sending_mode_mismatch, MSG_CHAIN_CONFIG_VALIDATION_FAILED_ERR,
+        MSG_ROLLUP_CUSTOM_PUBDATA_SENDING_MODE_ERR,
+    },
+    utils::ports::{EcosystemPorts, EcosystemPortsScanner},
+};
+
+/// Pubdata sending mode used by chains that do not publish pubdata on L1.
+const CUSTOM_PUBDATA_SENDING_MODE: &str = "CUSTOM";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Severity {
+    Info,
+    Warning,
+    Error,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ValidationIssue {
+    pub severity: Severity,
+    pub message: String,
+}
+
+impl ValidationIssue {
+    fn error(message: impl Into<String>) -> Self {
+        Self {
+            severity: Severity::Error,
+            message: message.into(),
+        }
+    }
+
+    fn warning(message: impl Into<String>) -> Self {
+        Self {
+            severity: Severity::Warning,
+            message: message.into(),
+        }
+    }
+
+    fn info(message: impl Into<String>) -> Self {
+        Self {
+            severity: Severity::Info,
+            message: message.into(),
+        }
+    }
+}
+
+/// Sanity checks run before `chain init` writes any config files.
+///
+/// The checks are run against the default config templates, since these are the files
+/// `init_configs` copies into the chain config directory.
+pub struct ChainConfigValidator<'a> {
+    shell: &'a Shell,
+}
+
+impl<'a> ChainConfigValidator<'a> {
+    pub fn new(shell: &'a Shell) -> Self {
+        Self { shell }
+    }
+
+    pub async fn validate(
+        &self,
+        chain_config: &ChainConfig,
+        init_args: &InitConfigsArgsFinal,
+    ) -> anyhow::Result<Vec<ValidationIssue>> {
+        let mut issues = vec![];
+
+        let default_general_config_path = chain_config.default_configs_path().join(GENERAL_FILE);
+        // With port reallocation enabled, `init_configs` resolves the conflicts itself.
+        if init_args.no_port_reallocation {
+            let chain_ports =
+                EcosystemPortsScanner::scan_file(self.shell, &default_general_config_path)?;
+            let ecosystem_ports =
+                EcosystemPortsScanner::scan(self.shell, Some(&chain_config.name))?;
+            issues.extend(port_conflicts(&chain_ports, &ecosystem_ports));
+        }
+
+        if !init_args.skip_l1_check {
+            if let Err(err) =
+                check_l1_chain_id(chain_config.l1_network, &init_args.l1_rpc_url).await
+            {
+                issues.push(ValidationIssue::error(format!("{err:#}")));
+            }
+            issues.extend(check_base_token(&chain_config.base_token, &init_args.l1_rpc_url).await);
+        }
+
+        if let Some(max_block_age) = init_args.max_l1_block_age {
+            if let Err(err) = check_l1_block_age(&init_args.l1_rpc_url, max_block_age).await {
+                issues.push(ValidationIssue::error(format!("{err:#}")));
+            }
+        }
+
+        let default_general_config = GeneralConfig::read(self.shell, &default_general_config_path)
+            .await
+            .context("failed reading default general config")?;
+        let pubdata_sending_mode = default_general_config
+            .get_raw("eth.sender.pubdata_sending_mode")
+            .and_then(|mode| mode.as_str());
+        issues.extend(check_pubdata_sending_mode(
+            chain_config.l1_batch_commit_data_generator_mode,
+            pubdata_sending_mode,
+            init_args.genesis_args.is_some(),
+        ));
+
+        issues.sort_by(|a, b| b.severity.cmp(&a.severity));
+        Ok(issues)
+    }
+}
+
+/// Logs the validation issues and fails if any of them is an error.
+pub fn report_validation_issues(issues: &[ValidationIssue]) -> anyhow::Result<()> {
+    for issue in issues {
+        match issue.severity {
+            Severity::Error => logger::error(&issue.message),
+            Severity::Warning => logger::warn(&issue.message),
+            Severity::Info => logger::info(&issue.message),
+        }
+    }
+    anyhow::ensure!(
+        issues.iter().all(|issue| issue.severity != Severity::Error),
+        MSG_CHAIN_CONFIG_VALIDATION_FAILED_ERR
+    );
+    Ok(())
+}
+
+fn port_conflicts(
+    chain_ports: &EcosystemPorts,
+    ecosystem_ports: &EcosystemPorts,
+) -> Vec<ValidationIssue> {
+    let mut conflicts: Vec<_> = chain_ports
+        .ports
+        .iter()
+        .filter_map(|(port, chain_infos)| {
+            let used_by = ecosystem_ports.ports.get(port)?;
+            Some(ValidationIssue::error(msg_port_conflict(
+                *port,
+                &chain_infos[0].description,
+                &used_by[0].to_string(),
+            )))
+        })
+        .collect();
+    conflicts.sort_by(|a, b| a.message.cmp(&b.message));
+    conflicts
+}
+
+async fn check_base_token(base_token: &BaseToken, l1_rpc_url: &str) -> Option<ValidationIssue> {
+    // ETH has no token contract, regardless of the configured price ratio.
+    if base_token.address == ETH_ADDRESS {
+        return None;
+    }
+    let code = match get_ethers_provider(l1_rpc_url) {
+        Ok(provider) => provider.get_code(base_token.address, None).await,
+        Err(err) => return Some(ValidationIssue::error(format!("{err:#}"))),
+    };
+    match code {
+        Ok(code) if code.is_empty() => Some(ValidationIssue::error(msg_base_token_not_deployed(
+            base_token.address,
+        ))),
+        Ok(_) => None,
+        Err(err) => Some(ValidationIssue::warning(msg_base_token_check_failed(
+            base_token.address,
+            &err,
+        ))),
+    }
+}
+
+/// Checks that the pubdata sending mode of the general config agrees with the commitment mode
+/// written to the genesis config.
+fn check_pubdata_sending_mode(
+    commitment_mode: L1BatchCommitmentMode,
+    pubdata_sending_mode: Option<&str>,
+    run_genesis: bool,
+) -> Option<ValidationIssue> {
+    let is_custom = pubdata_sending_mode == Some(CUSTOM_PUBDATA_SENDING_MODE);
+    match commitment_mode {
+        L1BatchCommitmentMode::Rollup if is_custom => Some(ValidationIssue::error(
+            MSG_ROLLUP_CUSTOM_PUBDATA_SENDING_MODE_ERR,
+        )),
+        L1BatchCommitmentMode::Validium if !is_custom => {
+            let message = msg_pubdata_sending_mode_mismatch(
+                pubdata_sending_mode.unwrap_or("<unset>"),
+                run_genesis,
+            );
+            // Genesis applies the validium override config, which fixes the mode.
+            Some(if run_genesis {
+                ValidationIssue::info(message)
+            } else {
+                ValidationIssue::warning(message)
+            })
+        }
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::ports::PortInfo;
+
+    fn ports(entries: &[(u16, &str)]) -> EcosystemPorts {
+        let mut ports = EcosystemPorts::default();
+        for &(port, description) in entries {
+            ports.add_port_info(
+                port,
+                PortInfo {
+                    port,
+                    file_path: "general.yaml".to_owned(),
+                    description: description.to_owned(),
+                },
+            );
+        }
+        ports
+    }
+
+    #[test]
+    fn test_port_conflicts() {
+        let chain_ports = ports(&[(3050, "api:web3_json_rpc:http_port"), (3071, "healthcheck")]);
+        let ecosystem_ports = ports(&[(3050, "api:web3_json_rpc:http_port"), (5432, "postgres")]);
+
+        let issues = port_conflicts(&chain_ports, &ecosystem_ports);
+        assert_eq!(issues.len(), 1);
+        assert_eq!(issues[0].severity, Severity::Error);
+        assert!(issues[0].message.contains("3050"), "{}", issues[0].message);
+    }
+
+    #[tokio::test]
+    async fn test_check_base_token_skips_eth_with_custom_ratio() {
+        let base_token = BaseToken {
+            address: ETH_ADDRESS,
+            nominator: 3,
+            denominator: 2,
+        };
+        // The RPC URL is never used for ETH, so an unreachable one must not produce an issue.
+        assert_eq!(
+            check_base_token(&base_token, "http://127.0.0.1:1").await,
+            None
+        );
+    }
+
+    #[test]
+    fn test_check_pubdata_sending_mode() {
+        assert_eq!(
+            check_pubdata_sending_mode(L1BatchCommitmentMode::Rollup, Some("BLOBS"), false),
+            None
+        );
+        assert_eq!(
+            check_pubdata_sending_mode(L1BatchCommitmentMode::Validium, Some("CUSTOM"), false),
+            None
+        );
+        assert_eq!(
+            check_pubdata_sending_mode(L1BatchCommitmentMode::Rollup, Some("CUSTOM"), true)
+                .map(|issue| issue.severity),
+            Some(Severity::Error)
+        );
+        assert_eq!(
+            check_pubdata_sending_mode(L1BatchCommitmentMode::Validium, Some("BLOBS"), true)
+                .map(|issue| issue.severity),
+            Some(Severity::Info)
+        );
+        assert_eq!(
+            check_pubdata_sending_mode(L1BatchCommitmentMode::Validium, Some("BLOBS"), false)
+                .map(|issue| issue.severity),
+            Some(Severity::Warning)
+        );
+    }
+}
@@ -1,11 +1,17 @@
 use ::zkstack_cli_common::forge::ForgeScriptArgs;
 use args::build_transactions::BuildTransactionsArgs;
 pub(crate) use args::create::ChainCreateArgsFinal;
+use build_admin_batch::BuildAdminBatchArgs;
 use clap::{command, Subcommand};
 pub(crate) use create::create_chain_inner;
+use deploy_consensus_registry::DeployConsensusRegistryArgs;
+use register_chain::RegisterChainArgs;
 use set_da_validator_pair::SetDAValidatorPairArgs;
 use set_da_validator_pair_calldata::SetDAValidatorPairCalldataArgs;
 use set_transaction_filterer::SetTransactionFiltererArgs;
+use show_fee_config::ShowFeeConfigArgs;
+use upgrade_da_validator::UpgradeDAValidatorArgs;
+use wait_for_genesis::WaitForGenesisArgs;
 use xshell::Shell;
 
 use crate::commands::chain::{
@@ -18,9 +24,11 @@ use crate::commands::chain::{
 mod accept_chain_ownership;
 pub(crate) mod admin_call_builder;
 pub(crate) mod args;
+mod build_admin_batch;
 mod build_transactions;
 pub(crate) mod common;
 pub(crate) mod create;
+mod deploy_consensus_registry;
 pub mod deploy_l2_contracts;
 pub mod deploy_paymaster;
 mod enable_evm_emulator;
@@ -34,7 +42,10 @@ mod set_pubdata_pricing_mode;
 mod set_token_multiplier_setter;
 pub(crate) mod set_transaction_filterer;
 mod setup_legacy_bridge;
+mod show_fee_config;
+mod upgrade_da_validator;
 pub mod utils;
+mod wait_for_genesis;
 
 #[derive(Subcommand, Debug)]
 pub enum ChainCommands {
@@ -46,12 +57,14 @@ pub enum ChainCommands {
     Init(Box<ChainInitCommand>),
     /// Run server genesis
     Genesis(GenesisCommand),
+    /// Wait until the chain's main node serves the genesis L2 block
+    WaitForGenesis(WaitForGenesisArgs),
     /// Register a new chain on L1 (executed by L1 governor).
     /// This command deploys and configures Governance, ChainAdmin, and DiamondProxy contracts,
     /// registers chain with BridgeHub and sets pending admin for DiamondProxy.
     /// Note: After completion, L2 governor can accept ownership by running `accept-chain-ownership`
     #[command(alias = "register")]
-    RegisterChain(ForgeScriptArgs),
+    RegisterChain(RegisterChainArgs),
     /// Deploy all L2 contracts (executed by L1 governor).
     #[command(alias = "l2")]
     DeployL2Contracts(ForgeScriptArgs),
@@ -62,7 +75,7 @@ pub enum ChainCommands {
     AcceptChainOwnership(ForgeScriptArgs),
     /// Deploy L2 consensus registry
     #[command(alias = "consensus")]
-    DeployConsensusRegistry(ForgeScriptArgs),
+    DeployConsensusRegistry(DeployConsensusRegistryArgs),
     /// Deploy L2 multicall3
     #[command(alias = "multicall3")]
     DeployMulticall3(ForgeScriptArgs),
@@ -84,14 +97,21 @@ pub enum ChainCommands {
     SetTransactionFiltererCalldata(SetTransactionFiltererArgs),
     /// Provides calldata to set DA validator pair for a chain
     SetDAValidatorPairCalldata(SetDAValidatorPairCalldataArgs),
+    /// Provides calldata to perform several admin operations on a chain in a single multicall
+    BuildAdminBatch(BuildAdminBatchArgs),
     /// Enable EVM emulation on chain (Not supported yet)
     EnableEvmEmulator(ForgeScriptArgs),
     /// Update pubdata pricing mode (used for Rollup -> Validium migration)
     SetPubdataPricingMode(SetPubdataPricingModeArgs),
     /// Update da validator pair (used for Rollup -> Validium migration)
     SetDAValidatorPair(SetDAValidatorPairArgs),
+    /// Deploy a new L2 DA validator and set it as the DA validator pair of the chain
+    /// together with the L1 DA validator (executed by L1 governor)
+    UpgradeDAValidator(UpgradeDAValidatorArgs),
+    /// Show fee-related settings from the chain general config
+    ShowFeeConfig(ShowFeeConfigArgs),
     #[command(subcommand, alias = "gw")]
-    Gateway(gateway::GatewayComamnds),
+    Gateway(gateway::GatewayCommands),
 }
 
 pub(crate) async fn run(shell: &Shell, args: ChainCommands) -> anyhow::Result<()> {
@@ -100,13 +120,14 @@ pub(crate) async fn run(shell: &Shell, args: ChainCommands) -> anyhow::Result<()
         ChainCommands::Init(args) => init::run(*args, shell).await,
         ChainCommands::BuildTransactions(args) => build_transactions::run(args, shell).await,
         ChainCommands::Genesis(args) => genesis::run(args, shell).await,
+        ChainCommands::WaitForGenesis(args) => wait_for_genesis::run(args, shell).await,
         ChainCommands::RegisterChain(args) => register_chain::run(args, shell).await,
         ChainCommands::DeployL2Contracts(args) => {
             deploy_l2_contracts::run(args, shell, Deploy2ContractsOption::All).await
         }
         ChainCommands::AcceptChainOwnership(args) => accept_chain_ownership::run(args, shell).await,
         ChainCommands::DeployConsensusRegistry(args) => {
-            deploy_l2_contracts::run(args, shell, Deploy2ContractsOption::ConsensusRegistry).await
+            deploy_consensus_registry::run(args, shell).await
         }
         ChainCommands::DeployMulticall3(args) => {
             deploy_l2_contracts::run(args, shell, Deploy2ContractsOption::Multicall3).await
@@ -130,11 +151,14 @@ pub(crate) async fn run(shell: &Shell, args: ChainCommands) -> anyhow::Result<()
         ChainCommands::SetDAValidatorPairCalldata(args) => {
             set_da_validator_pair_calldata::run(shell, args).await
         }
+        ChainCommands::BuildAdminBatch(args) => build_admin_batch::run(args).await,
         ChainCommands::EnableEvmEmulator(args) => enable_evm_emulator::run(args, shell).await,
         ChainCommands::SetPubdataPricingMode(args) => {
             set_pubdata_pricing_mode::run(args, shell).await
         }
         ChainCommands::SetDAValidatorPair(args) => set_da_validator_pair::run(args, shell).await,
+        ChainCommands::UpgradeDAValidator(args) => upgrade_da_validator::run(args, shell).await,
+        ChainCommands::ShowFeeConfig(args) => show_fee_config::run(args, shell).await,
         ChainCommands::Gateway(args) => gateway::run(shell, args).await,
     }
 }
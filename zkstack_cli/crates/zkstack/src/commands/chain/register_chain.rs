@@ -1,6 +1,10 @@
 use anyhow::Context;
+use clap::Parser;
+use ethers::providers::Middleware;
+use serde::{Deserialize, Serialize};
 use xshell::Shell;
 use zkstack_cli_common::{
+    ethereum::get_ethers_provider,
     forge::{Forge, ForgeScriptArgs},
     logger,
     spinner::Spinner,
@@ -14,13 +18,35 @@ use zkstack_cli_config::{
     ChainConfig, ContractsConfig, CoreContractsConfig, EcosystemConfig, ZkStackConfig,
     ZkStackConfigTrait,
 };
+use zksync_basic_types::Address;
 
 use crate::{
-    messages::{MSG_CHAIN_NOT_INITIALIZED, MSG_CHAIN_REGISTERED, MSG_REGISTERING_CHAIN_SPINNER},
+    abi::BridgehubAbi,
+    messages::{
+        msg_contracts_without_code, msg_registered_diamond_proxy_mismatch,
+        MSG_CHAIN_ALREADY_REGISTERED, MSG_CHAIN_NOT_INITIALIZED, MSG_CHAIN_NOT_REGISTERED,
+        MSG_CHAIN_REGISTERED, MSG_NO_CHAIN_CONTRACTS_CONFIG_ERR, MSG_REGISTERING_CHAIN_SPINNER,
+    },
     utils::forge::{check_the_balance, fill_forge_private_key, WalletOwner},
 };
 
-pub async fn run(args: ForgeScriptArgs, shell: &Shell) -> anyhow::Result<()> {
+#[derive(Debug, Serialize, Deserialize, Parser)]
+pub struct RegisterChainArgs {
+    #[clap(flatten)]
+    #[serde(flatten)]
+    pub forge_args: ForgeScriptArgs,
+    /// Skip the registration if the diamond proxy from the chain's contracts config is
+    /// already registered in the bridgehub, e.g. when resubmitting after an L1 reorg.
+    /// If the chain is not registered, it is registered from scratch: the existing
+    /// contract addresses are not passed to the registration script
+    #[clap(long)]
+    pub skip_if_registered: bool,
+    /// Check that the already registered contracts have code on L1
+    #[clap(long, requires = "skip_if_registered")]
+    pub verify_on_chain: bool,
+}
+
+pub async fn run(args: RegisterChainArgs, shell: &Shell) -> anyhow::Result<()> {
     let ecosystem_config = ZkStackConfig::ecosystem(shell)?;
     let chain_config = ecosystem_config
         .load_current_chain()
@@ -28,10 +54,35 @@ pub async fn run(args: ForgeScriptArgs, shell: &Shell) -> anyhow::Result<()> {
     let contracts = ecosystem_config.get_contracts_config()?;
     let secrets = chain_config.get_secrets_config().await?;
     let l1_rpc_url = secrets.l1_rpc_url()?;
+
+    if args.skip_if_registered {
+        let chain_contracts = chain_config
+            .get_contracts_config()
+            .context(MSG_NO_CHAIN_CONTRACTS_CONFIG_ERR)?;
+        let bridgehub = BridgehubAbi::new(
+            contracts.core_ecosystem_contracts.bridgehub_proxy_addr,
+            get_ethers_provider(&l1_rpc_url)?,
+        );
+        let registered_diamond_proxy = bridgehub
+            .get_zk_chain(chain_config.chain_id.as_u64().into())
+            .await?;
+        if is_registered(
+            registered_diamond_proxy,
+            chain_contracts.l1.diamond_proxy_addr,
+        )? {
+            if args.verify_on_chain {
+                verify_contracts_deployed(&chain_contracts, &l1_rpc_url).await?;
+            }
+            logger::success(MSG_CHAIN_ALREADY_REGISTERED);
+            return Ok(());
+        }
+        logger::info(MSG_CHAIN_NOT_REGISTERED);
+    }
+
     let spinner = Spinner::new(MSG_REGISTERING_CHAIN_SPINNER);
     let contracts = register_chain(
         shell,
-        args,
+        args.forge_args,
         &ecosystem_config,
         &chain_config,
         &contracts,
@@ -46,6 +97,37 @@ pub async fn run(args: ForgeScriptArgs, shell: &Shell) -> anyhow::Result<()> {
     Ok(())
 }
 
+/// Returns whether the bridgehub already points to the given diamond proxy.
+fn is_registered(registered: Address, diamond_proxy: Address) -> anyhow::Result<bool> {
+    if registered == Address::zero() {
+        return Ok(false);
+    }
+    anyhow::ensure!(
+        registered == diamond_proxy,
+        msg_registered_diamond_proxy_mismatch(registered, diamond_proxy)
+    );
+    Ok(true)
+}
+
+async fn verify_contracts_deployed(
+    contracts: &ContractsConfig,
+    l1_rpc_url: &str,
+) -> anyhow::Result<()> {
+    let provider = get_ethers_provider(l1_rpc_url)?;
+    let mut missing = vec![];
+    for (name, address) in [
+        ("DiamondProxy", contracts.l1.diamond_proxy_addr),
+        ("Governance", contracts.l1.governance_addr),
+        ("ChainAdmin", contracts.l1.chain_admin_addr),
+    ] {
+        if provider.get_code(address, None).await?.is_empty() {
+            missing.push(format!("{name} ({address:?})"));
+        }
+    }
+    anyhow::ensure!(missing.is_empty(), msg_contracts_without_code(&missing));
+    Ok(())
+}
+
 #[allow(clippy::too_many_arguments)]
 pub async fn register_chain(
     shell: &Shell,
@@ -93,3 +175,16 @@ pub async fn register_chain(
         contracts.chain_contracts_from_output(&register_chain_output, chain_config);
     Ok(full_contracts)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_registered() {
+        let diamond_proxy = Address::repeat_byte(0x11);
+        assert!(!is_registered(Address::zero(), diamond_proxy).unwrap());
+        assert!(is_registered(diamond_proxy, diamond_proxy).unwrap());
+        assert!(is_registered(Address::repeat_byte(0x22), diamond_proxy).is_err());
+    }
+}
@@ -11,14 +11,23 @@ use zksync_basic_types::Address;
 use zksync_system_constants::L2_BRIDGEHUB_ADDRESS;
 use zksync_web3_decl::jsonrpsee::core::Serialize;
 
+use super::{
+    admin_call_builder::AdminCallBuilder,
+    utils::{display_admin_script_output, send_tx},
+};
 use crate::{
     abi::{BridgehubAbi, ZkChainAbi},
-    admin_functions::{set_da_validator_pair, set_da_validator_pair_via_gateway, AdminScriptMode},
+    admin_functions::{
+        set_da_validator_pair, set_da_validator_pair_via_gateway, AdminScriptMode,
+        AdminScriptOutput,
+    },
     messages::{
-        MSG_CHAIN_NOT_INITIALIZED, MSG_DA_VALIDATOR_PAIR_UPDATED_TO,
-        MSG_GATEWAY_URL_MUST_BE_PRESET, MSG_GOT_SETTLEMENT_LAYER_ADDRESS_FROM_GW,
+        msg_wallet_private_key_not_set, MSG_CHAIN_NOT_INITIALIZED,
+        MSG_DA_VALIDATOR_PAIR_UPDATED_TO, MSG_GATEWAY_URL_MUST_BE_PRESET,
+        MSG_GOT_SETTLEMENT_LAYER_ADDRESS_FROM_GW, MSG_NO_FORGE_HELP, MSG_SUBMIT_HELP,
         MSG_UPDATING_DA_VALIDATOR_PAIR_SPINNER, MSG_USE_GATEWAY_HELP,
     },
+    utils::forge::WalletOwner,
 };
 
 #[derive(Debug, Serialize, Deserialize, Parser)]
@@ -36,6 +45,10 @@ pub struct SetDAValidatorPairArgs {
     pub max_l1_gas_price: Option<u64>,
     #[clap(long, help = MSG_USE_GATEWAY_HELP)]
     pub gateway: bool,
+    #[clap(long, help = MSG_NO_FORGE_HELP, conflicts_with = "gateway")]
+    pub no_forge: bool,
+    #[clap(long, help = MSG_SUBMIT_HELP, requires = "no_forge")]
+    pub submit: bool,
 }
 
 pub async fn run(args: SetDAValidatorPairArgs, shell: &Shell) -> anyhow::Result<()> {
@@ -53,6 +66,46 @@ pub async fn run(args: SetDAValidatorPairArgs, shell: &Shell) -> anyhow::Result<
         .l1_rpc_url()?
         .to_string();
 
+    if args.no_forge {
+        let mut builder = AdminCallBuilder::new(vec![]);
+        builder.append_set_da_validator_pair(
+            contracts_config.l1.diamond_proxy_addr,
+            args.l1_da_validator,
+            l2_da_validator_address,
+        )?;
+        if !args.submit {
+            display_admin_script_output(AdminScriptOutput {
+                admin_address: contracts_config.l1.chain_admin_addr,
+                calls: builder.into_calls(),
+            });
+            return Ok(());
+        }
+
+        let governor = chain_config.get_wallets_config()?.governor;
+        let private_key = governor
+            .private_key_h256()
+            .context(msg_wallet_private_key_not_set(WalletOwner::Governor))?;
+        let (data, value) = builder.compile_full_calldata();
+        send_tx(
+            contracts_config.l1.chain_admin_addr,
+            data,
+            value,
+            l1_rpc_url,
+            private_key,
+            "setting DA validator pair",
+        )
+        .await?;
+        logger::note(
+            MSG_DA_VALIDATOR_PAIR_UPDATED_TO,
+            format!(
+                "{} {}",
+                hex::encode(args.l1_da_validator),
+                hex::encode(l2_da_validator_address)
+            ),
+        );
+        return Ok(());
+    }
+
     let spinner = Spinner::new(MSG_UPDATING_DA_VALIDATOR_PAIR_SPINNER);
 
     if args.gateway {
@@ -152,3 +205,49 @@ pub async fn run(args: SetDAValidatorPairArgs, shell: &Shell) -> anyhow::Result<
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use ethers::utils::keccak256;
+
+    use super::*;
+
+    #[test]
+    fn test_encode_set_da_validator_pair() -> anyhow::Result<()> {
+        let diamond_proxy = Address::repeat_byte(0x01);
+        let l1 = Address::repeat_byte(0x11);
+        let l2 = Address::repeat_byte(0x22);
+        let mut builder = AdminCallBuilder::new(vec![]);
+        builder.append_set_da_validator_pair(diamond_proxy, l1, l2)?;
+        let calls = builder.into_calls();
+
+        assert_eq!(calls.len(), 1);
+        assert_eq!(calls[0].target, diamond_proxy);
+        let data = &calls[0].data;
+        assert_eq!(data.len(), 4 + 2 * 32);
+        assert_eq!(
+            data[..4],
+            keccak256("setDAValidatorPair(address,address)")[..4]
+        );
+        assert_eq!(data[16..36], *l1.as_bytes());
+        assert_eq!(data[48..68], *l2.as_bytes());
+        Ok(())
+    }
+
+    #[test]
+    fn test_encode_set_da_validator_pair_rejects_invalid_pairs() {
+        let diamond_proxy = Address::repeat_byte(0x01);
+        let l1 = Address::repeat_byte(0x11);
+        let mut builder = AdminCallBuilder::new(vec![]);
+        assert!(builder
+            .append_set_da_validator_pair(diamond_proxy, Address::zero(), l1)
+            .is_err());
+        assert!(builder
+            .append_set_da_validator_pair(diamond_proxy, l1, Address::zero())
+            .is_err());
+        assert!(builder
+            .append_set_da_validator_pair(diamond_proxy, l1, l1)
+            .is_err());
+        assert!(builder.into_calls().is_empty());
+    }
+}
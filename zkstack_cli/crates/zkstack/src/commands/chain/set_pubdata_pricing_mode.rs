@@ -5,6 +5,7 @@ use ethers::{abi::parse_abi, contract::BaseContract};
 use lazy_static::lazy_static;
 use xshell::Shell;
 use zkstack_cli_common::{
+    ethereum::get_ethers_provider,
     forge::{Forge, ForgeScript, ForgeScriptArgs},
     logger,
     spinner::Spinner,
@@ -17,9 +18,11 @@ use zkstack_cli_config::{
 use zksync_basic_types::Address;
 
 use crate::{
+    abi::ZkChainAbi,
     commands::chain::args::set_pubdata_pricing_mode::SetPubdataPricingModeArgs,
     messages::{
-        MSG_CHAIN_NOT_INITIALIZED, MSG_PUBDATA_PRICING_MODE_UPDATED_TO,
+        msg_pubdata_pricing_mode_already_set, MSG_CHAIN_NOT_INITIALIZED,
+        MSG_CHECKING_CURRENT_PUBDATA_PRICING_MODE_SPINNER, MSG_PUBDATA_PRICING_MODE_UPDATED_TO,
         MSG_UPDATING_PUBDATA_PRICING_MODE_SPINNER,
     },
     utils::forge::{check_the_balance, fill_forge_private_key, WalletOwner},
@@ -40,6 +43,19 @@ pub async fn run(args: SetPubdataPricingModeArgs, shell: &Shell) -> anyhow::Resu
     let l1_url = chain_config.get_secrets_config().await?.l1_rpc_url()?;
     let pubdata_pricing_mode: u8 = if args.rollup.unwrap() { 0 } else { 1 };
 
+    if args.check_current {
+        let spinner = Spinner::new(MSG_CHECKING_CURRENT_PUBDATA_PRICING_MODE_SPINNER);
+        let current_mode =
+            get_pubdata_pricing_mode(&l1_url, contracts_config.l1.diamond_proxy_addr).await?;
+        spinner.finish();
+        if current_mode == pubdata_pricing_mode {
+            logger::warn(msg_pubdata_pricing_mode_already_set(
+                &pubdata_pricing_mode_name(current_mode),
+            ));
+            return Ok(());
+        }
+    }
+
     let spinner = Spinner::new(MSG_UPDATING_PUBDATA_PRICING_MODE_SPINNER);
     set_pubdata_pricing_mode(
         shell,
@@ -60,6 +76,26 @@ pub async fn run(args: SetPubdataPricingModeArgs, shell: &Shell) -> anyhow::Resu
     Ok(())
 }
 
+/// Names `PubdataPricingMode` values from the contracts, which are `Rollup = 0` and `Validium = 1`.
+fn pubdata_pricing_mode_name(mode: u8) -> String {
+    match mode {
+        0 => "Rollup".to_owned(),
+        1 => "Validium".to_owned(),
+        _ => format!("unknown ({mode})"),
+    }
+}
+
+async fn get_pubdata_pricing_mode(
+    l1_rpc_url: &str,
+    diamond_proxy_address: Address,
+) -> anyhow::Result<u8> {
+    let provider = get_ethers_provider(l1_rpc_url)?;
+    ZkChainAbi::new(diamond_proxy_address, provider)
+        .get_pubdata_pricing_mode()
+        .await
+        .context("failed to query current pubdata pricing mode")
+}
+
 #[allow(clippy::too_many_arguments)]
 pub async fn set_pubdata_pricing_mode(
     shell: &Shell,
@@ -1,12 +1,48 @@
 use anyhow::Context;
-use clap::Parser;
+use clap::{ArgAction, Parser};
+use ethers::{
+    abi::parse_abi,
+    contract::BaseContract,
+    providers::{Http, Middleware, Provider},
+    types::{Bytes, TransactionRequest},
+};
+use lazy_static::lazy_static;
 use serde::{Deserialize, Serialize};
 use xshell::Shell;
+use zkstack_cli_common::{ethereum::get_ethers_provider, logger, spinner::Spinner};
 use zkstack_cli_config::ZkStackConfigTrait;
-use zksync_types::Address;
+use zksync_types::{Address, U256};
 
 use super::utils::display_admin_script_output;
-use crate::admin_functions::{set_transaction_filterer, AdminScriptMode};
+use crate::{
+    admin_functions::{set_transaction_filterer, AdminScriptMode},
+    messages::{
+        msg_transaction_filterer_no_code, MSG_TRANSACTION_FILTERER_CALL_FAILED_ERR,
+        MSG_TRANSACTION_FILTERER_FORCE_HINT, MSG_TRANSACTION_FILTERER_INTERFACE_NOT_SUPPORTED_ERR,
+        MSG_TRANSACTION_FILTERER_INVALID_OUTPUT_ERR, MSG_TRANSACTION_FILTERER_UNSET,
+        MSG_VERIFYING_TRANSACTION_FILTERER_SPINNER,
+    },
+};
+
+lazy_static! {
+    static ref ERC165: BaseContract = BaseContract::from(
+        parse_abi(&[
+            "function supportsInterface(bytes4 interfaceId) external view returns (bool)"
+        ])
+        .unwrap(),
+    );
+    static ref TRANSACTION_FILTERER: BaseContract = BaseContract::from(
+        parse_abi(&[
+            "function isTransactionAllowed(address sender, address contractL2, uint256 mintValue, uint256 l2Value, bytes l2Calldata, address refundRecipient) external view returns (bool)"
+        ])
+        .unwrap(),
+    );
+}
+
+const ERC165_INTERFACE_ID: [u8; 4] = [0x01, 0xff, 0xc9, 0xa7];
+const INVALID_INTERFACE_ID: [u8; 4] = [0xff; 4];
+/// `ITransactionFilterer` declares only `isTransactionAllowed`, so its interface ID is that selector.
+const TRANSACTION_FILTERER_INTERFACE_ID: [u8; 4] = [0xcb, 0xcf, 0x2e, 0x3c];
 
 #[derive(Debug, Serialize, Deserialize, Parser)]
 pub struct SetTransactionFiltererArgs {
@@ -20,11 +56,34 @@ pub struct SetTransactionFiltererArgs {
     pub chain_id: u64,
 
     pub l1_rpc_url: String,
+
+    /// Check that the transaction filterer implements `ITransactionFilterer`
+    #[clap(long, default_value_t = true, default_missing_value = "true", num_args = 0..=1, action = ArgAction::Set)]
+    pub verify_abi: bool,
+
+    /// Proceed even if the transaction filterer check fails
+    #[clap(long)]
+    pub force: bool,
 }
 
 pub async fn run(shell: &Shell, args: SetTransactionFiltererArgs) -> anyhow::Result<()> {
     let chain_config = zkstack_cli_config::ZkStackConfig::current_chain(shell)
         .context("Failed to load the current chain configuration")?;
+
+    if args.transaction_filterer.is_zero() {
+        logger::info(MSG_TRANSACTION_FILTERER_UNSET);
+    } else if args.verify_abi {
+        let spinner = Spinner::new(MSG_VERIFYING_TRANSACTION_FILTERER_SPINNER);
+        let result = verify_transaction_filterer(&args.l1_rpc_url, args.transaction_filterer).await;
+        spinner.finish();
+        if let Err(err) = result {
+            if !args.force {
+                return Err(err.context(MSG_TRANSACTION_FILTERER_FORCE_HINT));
+            }
+            logger::warn(format!("{err:#}"));
+        }
+    }
+
     let result = set_transaction_filterer(
         shell,
         &Default::default(),
@@ -41,3 +100,123 @@ pub async fn run(shell: &Shell, args: SetTransactionFiltererArgs) -> anyhow::Res
 
     Ok(())
 }
+
+/// Checks that the contract at `filterer` implements `ITransactionFilterer`.
+///
+/// Filterers implementing ERC-165 must report the `ITransactionFilterer` interface. Others,
+/// like the `GatewayTransactionFilterer`, are checked by calling `isTransactionAllowed`.
+async fn verify_transaction_filterer(l1_rpc_url: &str, filterer: Address) -> anyhow::Result<()> {
+    let provider = get_ethers_provider(l1_rpc_url)?;
+    anyhow::ensure!(
+        !provider.get_code(filterer, None).await?.is_empty(),
+        msg_transaction_filterer_no_code(filterer)
+    );
+
+    if supports_erc165(&provider, filterer).await {
+        anyhow::ensure!(
+            supports_interface(&provider, filterer, TRANSACTION_FILTERER_INTERFACE_ID).await,
+            MSG_TRANSACTION_FILTERER_INTERFACE_NOT_SUPPORTED_ERR
+        );
+        return Ok(());
+    }
+
+    let data = TRANSACTION_FILTERER.encode(
+        "isTransactionAllowed",
+        (
+            Address::zero(),
+            Address::zero(),
+            U256::zero(),
+            U256::zero(),
+            Bytes::new(),
+            Address::zero(),
+        ),
+    )?;
+    let tx = TransactionRequest::new().to(filterer).data(data);
+    let output = provider
+        .call(&tx.into(), None)
+        .await
+        .context(MSG_TRANSACTION_FILTERER_CALL_FAILED_ERR)?;
+    decode_is_transaction_allowed(&output)?;
+    Ok(())
+}
+
+/// ERC-165 detection: the contract must support the ERC-165 interface and reject `0xffffffff`.
+async fn supports_erc165(provider: &Provider<Http>, contract: Address) -> bool {
+    supports_interface(provider, contract, ERC165_INTERFACE_ID).await
+        && !supports_interface(provider, contract, INVALID_INTERFACE_ID).await
+}
+
+/// Returns whether `contract` reports support for `interface_id`. A reverting call or an
+/// invalid result means the interface is not supported, as ERC-165 specifies.
+async fn supports_interface(
+    provider: &Provider<Http>,
+    contract: Address,
+    interface_id: [u8; 4],
+) -> bool {
+    let Ok(data) = ERC165.encode("supportsInterface", interface_id) else {
+        return false;
+    };
+    let tx = TransactionRequest::new().to(contract).data(data);
+    match provider.call(&tx.into(), None).await {
+        Ok(output) => decode_supports_interface(&output).unwrap_or(false),
+        Err(_) => false,
+    }
+}
+
+fn decode_supports_interface(output: &[u8]) -> anyhow::Result<bool> {
+    Ok(ERC165.decode_output("supportsInterface", output)?)
+}
+
+fn decode_is_transaction_allowed(output: &[u8]) -> anyhow::Result<bool> {
+    TRANSACTION_FILTERER
+        .decode_output("isTransactionAllowed", output)
+        .context(MSG_TRANSACTION_FILTERER_INVALID_OUTPUT_ERR)
+}
+
+#[cfg(test)]
+mod tests {
+    use ethers::{
+        abi::{encode, Token},
+        utils::keccak256,
+    };
+
+    use super::*;
+
+    #[test]
+    fn test_decode_supports_interface() {
+        let output = encode(&[Token::Bool(true)]);
+        assert!(decode_supports_interface(&output).unwrap());
+
+        let output = encode(&[Token::Bool(false)]);
+        assert!(!decode_supports_interface(&output).unwrap());
+
+        assert!(decode_supports_interface(&[]).is_err());
+    }
+
+    #[test]
+    fn test_decode_is_transaction_allowed() {
+        let output = encode(&[Token::Bool(true)]);
+        assert!(decode_is_transaction_allowed(&output).unwrap());
+
+        let output = encode(&[Token::Bool(false)]);
+        assert!(!decode_is_transaction_allowed(&output).unwrap());
+
+        assert!(decode_is_transaction_allowed(&[]).is_err());
+    }
+
+    #[test]
+    fn test_interface_ids() {
+        assert_eq!(
+            ERC165_INTERFACE_ID,
+            keccak256("supportsInterface(bytes4)")[..4]
+        );
+        assert_eq!(
+            TRANSACTION_FILTERER_INTERFACE_ID,
+            TRANSACTION_FILTERER
+                .abi()
+                .function("isTransactionAllowed")
+                .unwrap()
+                .short_signature()
+        );
+    }
+}
@@ -0,0 +1,204 @@
+use anyhow::Context;
+use clap::{Parser, ValueEnum};
+use serde::{Deserialize, Serialize};
+use xshell::Shell;
+use zkstack_cli_common::logger;
+use zkstack_cli_config::{GeneralConfig, ZkStackConfig, ZkStackConfigTrait, GENERAL_FILE};
+use zkstack_cli_types::L1Network;
+
+use crate::messages::{
+    msg_fee_config_for_chain, MSG_CHAIN_NOT_INITIALIZED, MSG_FEE_CONFIG_MODIFIED_NOTE,
+};
+
+/// Fee-related params of the general config, in the order they are displayed.
+const FEE_CONFIG_PATHS: &[&str] = &[
+    "eth.sender.max_acceptable_base_fee_in_wei",
+    "eth.sender.max_acceptable_priority_fee_in_gwei",
+    "eth.sender.pubdata_sending_mode",
+    "eth.sender.max_txs_in_flight",
+    "eth.sender.tx_poll_period",
+    "eth.sender.aggregate_tx_poll_period",
+    "eth.sender.aggregated_block_commit_deadline",
+    "eth.gas_adjuster.default_priority_fee_per_gas",
+    "eth.gas_adjuster.internal_l1_pricing_multiplier",
+    "eth.gas_adjuster.internal_pubdata_pricing_multiplier",
+    "eth.gas_adjuster.max_base_fee_samples",
+    "eth.gas_adjuster.poll_period",
+    "eth.watcher.eth_node_poll_interval",
+    "eth.watcher.confirmations_for_eth_event",
+    "state_keeper.minimal_l2_gas_price",
+    "state_keeper.batch_overhead_l1_gas",
+    "state_keeper.fee_model_version",
+];
+
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, ValueEnum)]
+pub enum FeeConfigFormat {
+    #[default]
+    Text,
+    Json,
+}
+
+#[derive(Debug, Serialize, Deserialize, Parser)]
+pub struct ShowFeeConfigArgs {
+    /// Output format
+    #[clap(long, value_enum, default_value_t)]
+    pub format: FeeConfigFormat,
+}
+
+#[derive(Debug, Serialize)]
+struct FeeConfigReport {
+    chain: String,
+    l1_network: L1Network,
+    settings: Vec<FeeSetting>,
+}
+
+#[derive(Debug, Serialize, PartialEq)]
+struct FeeSetting {
+    path: &'static str,
+    value: Option<serde_yaml::Value>,
+    default: Option<serde_yaml::Value>,
+    /// Whether the value differs from the one in the default config template.
+    modified: bool,
+}
+
+pub async fn run(args: ShowFeeConfigArgs, shell: &Shell) -> anyhow::Result<()> {
+    let chain_config = ZkStackConfig::current_chain(shell).context(MSG_CHAIN_NOT_INITIALIZED)?;
+    let general_config = chain_config.get_general_config().await?;
+    let default_general_config = GeneralConfig::read(
+        shell,
+        &chain_config.default_configs_path().join(GENERAL_FILE),
+    )
+    .await
+    .context("failed reading default general config")?;
+
+    let report = FeeConfigReport {
+        chain: chain_config.name.clone(),
+        l1_network: chain_config.l1_network,
+        settings: collect_fee_settings(
+            |path| general_config.get_raw(path),
+            |path| default_general_config.get_raw(path),
+        ),
+    };
+
+    match args.format {
+        FeeConfigFormat::Json => logger::raw(serde_json::to_string_pretty(&report)?),
+        FeeConfigFormat::Text => print_report(&report),
+    }
+    Ok(())
+}
+
+fn collect_fee_settings<'a>(
+    current: impl Fn(&str) -> Option<&'a serde_yaml::Value>,
+    default: impl Fn(&str) -> Option<&'a serde_yaml::Value>,
+) -> Vec<FeeSetting> {
+    FEE_CONFIG_PATHS
+        .iter()
+        .map(|&path| {
+            let value = current(path).cloned();
+            let default = default(path).cloned();
+            FeeSetting {
+                path,
+                modified: value != default,
+                value,
+                default,
+            }
+        })
+        .collect()
+}
+
+fn print_report(report: &FeeConfigReport) {
+    logger::info(msg_fee_config_for_chain(&report.chain, report.l1_network));
+    let width = FEE_CONFIG_PATHS
+        .iter()
+        .map(|path| path.len())
+        .max()
+        .unwrap_or_default();
+    let lines: Vec<_> = report
+        .settings
+        .iter()
+        .map(|setting| {
+            let value = display_value(setting.value.as_ref());
+            if setting.modified {
+                let default = display_value(setting.default.as_ref());
+                format!("* {:width$}  {value} (default: {default})", setting.path)
+            } else {
+                format!("  {:width$}  {value}", setting.path)
+            }
+        })
+        .collect();
+    logger::raw(lines.join("\n"));
+    logger::info(MSG_FEE_CONFIG_MODIFIED_NOTE);
+}
+
+fn display_value(value: Option<&serde_yaml::Value>) -> String {
+    match value {
+        None => "<unset>".to_owned(),
+        Some(value) => serde_yaml::to_string(value)
+            .map(|s| s.trim_end().to_owned())
+            .unwrap_or_else(|_| format!("{value:?}")),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    async fn read_general_config(shell: &Shell, name: &str, yaml: &str) -> GeneralConfig {
+        let temp_dir = shell.create_temp_dir().unwrap();
+        let path = temp_dir.path().join(name);
+        shell.write_file(&path, yaml).unwrap();
+        GeneralConfig::read(shell, &path).await.unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_fee_settings_mark_modified_params() {
+        let shell = Shell::new().unwrap();
+        let default = read_general_config(
+            &shell,
+            "default_general.yaml",
+            r#"
+            eth:
+              sender:
+                max_txs_in_flight: 190
+                pubdata_sending_mode: BLOBS
+              watcher:
+                eth_node_poll_interval: 300
+            "#,
+        )
+        .await;
+        let current = read_general_config(
+            &shell,
+            "general.yaml",
+            r#"
+            eth:
+              sender:
+                max_txs_in_flight: 50
+                pubdata_sending_mode: BLOBS
+              watcher:
+                eth_node_poll_interval: 300
+                confirmations_for_eth_event: 2
+            "#,
+        )
+        .await;
+
+        let settings = collect_fee_settings(|p| current.get_raw(p), |p| default.get_raw(p));
+        assert_eq!(settings.len(), FEE_CONFIG_PATHS.len());
+
+        let modified: Vec<_> = settings
+            .iter()
+            .filter(|setting| setting.modified)
+            .map(|setting| setting.path)
+            .collect();
+        assert_eq!(
+            modified,
+            [
+                "eth.sender.max_txs_in_flight",
+                "eth.watcher.confirmations_for_eth_event"
+            ]
+        );
+
+        let in_flight = &settings[3];
+        assert_eq!(in_flight.value, Some(50.into()));
+        assert_eq!(in_flight.default, Some(190.into()));
+    }
+}
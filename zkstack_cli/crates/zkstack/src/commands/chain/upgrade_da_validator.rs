@@ -0,0 +1,185 @@
+use anyhow::Context;
+use clap::Parser;
+use serde::{Deserialize, Serialize};
+use xshell::Shell;
+use zkstack_cli_common::{forge::ForgeScriptArgs, logger, spinner::Spinner};
+use zkstack_cli_config::{traits::SaveConfigWithBasePath, ZkStackConfig};
+use zksync_basic_types::{Address, U256};
+
+use crate::{
+    commands::chain::{
+        admin_call_builder::AdminCallBuilder, deploy_l2_contracts::deploy_l2_da_validator,
+        init::get_l1_da_validator, utils::send_tx,
+    },
+    messages::{
+        msg_da_validator_pair_upgraded, msg_wallet_private_key_not_set, MSG_CHAIN_NOT_INITIALIZED,
+        MSG_DA_VALIDATOR_PAIR_ZERO_ADDRESS_ERR, MSG_DEPLOYING_L2_DA_VALIDATOR_SPINNER,
+    },
+    utils::forge::WalletOwner,
+};
+
+#[derive(Debug, Serialize, Deserialize, Parser)]
+pub struct UpgradeDAValidatorArgs {
+    /// All ethereum environment related arguments
+    #[clap(flatten)]
+    #[serde(flatten)]
+    pub forge_args: ForgeScriptArgs,
+
+    /// The L1 DA validator to pair with the new L2 DA validator.
+    /// Defaults to the one matching the chain's commitment mode
+    #[clap(long, value_parser = parse_non_zero_address)]
+    pub l1_da_validator: Option<Address>,
+}
+
+fn parse_non_zero_address(s: &str) -> Result<Address, String> {
+    let address: Address = s.parse().map_err(|e| format!("Invalid address: {e}"))?;
+    if address.is_zero() {
+        return Err(MSG_DA_VALIDATOR_PAIR_ZERO_ADDRESS_ERR.into());
+    }
+    Ok(address)
+}
+
+/// Deploys a new L2 DA validator and sets it, together with the L1 DA validator, as the DA validator pair of the chain.
+pub async fn run(args: UpgradeDAValidatorArgs, shell: &Shell) -> anyhow::Result<()> {
+    let ecosystem_config = ZkStackConfig::ecosystem(shell)?;
+    let chain_config = ecosystem_config
+        .load_current_chain()
+        .context(MSG_CHAIN_NOT_INITIALIZED)?;
+    let mut contracts = chain_config.get_contracts_config()?;
+    let l1_rpc_url = chain_config.get_secrets_config().await?.l1_rpc_url()?;
+
+    let l1_da_validator = match args.l1_da_validator {
+        Some(address) => address,
+        None => get_l1_da_validator(&chain_config)
+            .await
+            .context("l1_da_validator_addr")?,
+    };
+
+    let spinner = Spinner::new(MSG_DEPLOYING_L2_DA_VALIDATOR_SPINNER);
+    deploy_l2_da_validator(
+        shell,
+        &chain_config,
+        &ecosystem_config,
+        &mut contracts,
+        args.forge_args.clone(),
+        l1_rpc_url.clone(),
+    )
+    .await?;
+    // Save right away, so that the pair can be set with `set-da-validator-pair` if the next step fails.
+    contracts.save_with_base_path(shell, &chain_config.configs)?;
+    spinner.finish();
+    let l2_da_validator = contracts
+        .l2
+        .da_validator_addr
+        .context("da_validator_addr")?;
+
+    let (data, value) = set_da_validator_pair_calldata(
+        contracts.l1.diamond_proxy_addr,
+        l1_da_validator,
+        l2_da_validator,
+    )?;
+    let governor = chain_config.get_wallets_config()?.governor;
+    let private_key = governor
+        .private_key_h256()
+        .context(msg_wallet_private_key_not_set(WalletOwner::Governor))?;
+    send_tx(
+        contracts.l1.chain_admin_addr,
+        data,
+        value,
+        l1_rpc_url,
+        private_key,
+        "setting DA validator pair",
+    )
+    .await?;
+
+    logger::success(msg_da_validator_pair_upgraded(
+        l1_da_validator,
+        l2_da_validator,
+    ));
+    Ok(())
+}
+
+/// Encodes the chain admin call that sets the DA validator pair of the chain.
+fn set_da_validator_pair_calldata(
+    diamond_proxy: Address,
+    l1_da_validator: Address,
+    l2_da_validator: Address,
+) -> anyhow::Result<(Vec<u8>, U256)> {
+    let mut builder = AdminCallBuilder::new(vec![]);
+    builder.append_set_da_validator_pair(diamond_proxy, l1_da_validator, l2_da_validator)?;
+    Ok(builder.compile_full_calldata())
+}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::Context;
+    use ethers::abi::Token;
+
+    use super::*;
+    use crate::abi::{CHAINADMINOWNABLEABI_ABI, ZKCHAINABI_ABI};
+
+    fn parse_args(args: &[&str]) -> Result<UpgradeDAValidatorArgs, clap::Error> {
+        UpgradeDAValidatorArgs::try_parse_from(
+            std::iter::once("upgrade-da-validator").chain(args.iter().copied()),
+        )
+    }
+
+    #[test]
+    fn test_l1_da_validator_arg() {
+        let l1 = Address::repeat_byte(0x11);
+        assert_eq!(parse_args(&[]).unwrap().l1_da_validator, None);
+        assert_eq!(
+            parse_args(&["--l1-da-validator", &format!("{l1:?}")])
+                .unwrap()
+                .l1_da_validator,
+            Some(l1)
+        );
+        assert!(parse_args(&["--l1-da-validator", "0x1234"]).is_err());
+        assert!(parse_args(&["--l1-da-validator", &format!("{:?}", Address::zero())]).is_err());
+    }
+
+    #[test]
+    fn test_set_da_validator_pair_calldata() -> anyhow::Result<()> {
+        let diamond_proxy = Address::repeat_byte(0x01);
+        let l1 = Address::repeat_byte(0x11);
+        let l2 = Address::repeat_byte(0x22);
+        let (calldata, value) = set_da_validator_pair_calldata(diamond_proxy, l1, l2)?;
+        assert_eq!(value, U256::zero());
+
+        let multicall = CHAINADMINOWNABLEABI_ABI.function("multicall")?;
+        assert_eq!(calldata[..4], multicall.short_signature());
+        let mut inputs = multicall.decode_input(&calldata[4..])?;
+        assert_eq!(inputs.pop(), Some(Token::Bool(true)));
+        let mut calls = inputs
+            .pop()
+            .and_then(Token::into_array)
+            .context("multicall calls are not an array")?;
+        assert_eq!(calls.len(), 1);
+        let fields = calls
+            .pop()
+            .and_then(Token::into_tuple)
+            .context("call is not a tuple")?;
+        assert_eq!(fields[0], Token::Address(diamond_proxy));
+        assert_eq!(fields[1], Token::Uint(U256::zero()));
+
+        let data = fields[2]
+            .clone()
+            .into_bytes()
+            .context("call data is not bytes")?;
+        let function = ZKCHAINABI_ABI.function("setDAValidatorPair")?;
+        assert_eq!(data[..4], function.short_signature());
+        assert_eq!(
+            function.decode_input(&data[4..])?,
+            vec![Token::Address(l1), Token::Address(l2)]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_set_da_validator_pair_calldata_rejects_invalid_pairs() {
+        let diamond_proxy = Address::repeat_byte(0x01);
+        let l1 = Address::repeat_byte(0x11);
+        assert!(set_da_validator_pair_calldata(diamond_proxy, l1, Address::zero()).is_err());
+        assert!(set_da_validator_pair_calldata(diamond_proxy, l1, l1).is_err());
+    }
+}
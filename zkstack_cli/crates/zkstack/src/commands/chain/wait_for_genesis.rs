@@ -0,0 +1,80 @@
+use anyhow::Context;
+use clap::Parser;
+use serde::{Deserialize, Serialize};
+use tokio::time::MissedTickBehavior;
+use xshell::Shell;
+use zkstack_cli_common::{config::global_config, ethereum::get_zk_client, logger};
+use zkstack_cli_config::ZkStackConfig;
+use zksync_basic_types::L2BlockNumber;
+use zksync_web3_decl::{
+    client::{Client, L2},
+    namespaces::ZksNamespaceClient,
+};
+
+use crate::{
+    commands::args::WaitArgs,
+    messages::{
+        msg_genesis_block_found, msg_wait_genesis_block_not_available, MSG_CHAIN_NOT_INITIALIZED,
+        MSG_GENESIS_WAIT_COMPONENT, MSG_WAITING_FOR_GENESIS,
+    },
+};
+
+#[derive(Debug, Serialize, Deserialize, Parser)]
+#[command(
+    mut_arg("timeout", |arg| arg.default_value("300").alias("timeout-seconds")),
+    mut_arg("poll_interval", |arg| arg.default_value("2000").alias("poll-interval-ms"))
+)]
+pub struct WaitForGenesisArgs {
+    #[clap(flatten)]
+    #[serde(flatten)]
+    pub wait: WaitArgs,
+    /// Do not print progress messages
+    #[clap(long)]
+    pub silent: bool,
+}
+
+pub async fn run(args: WaitForGenesisArgs, shell: &Shell) -> anyhow::Result<()> {
+    let chain_config = ZkStackConfig::current_chain(shell).context(MSG_CHAIN_NOT_INITIALIZED)?;
+    let l2_rpc_url = chain_config.get_general_config().await?.l2_http_url()?;
+    let client = get_zk_client(&l2_rpc_url, chain_config.chain_id.as_u64())?;
+
+    if !args.silent {
+        logger::info(MSG_WAITING_FOR_GENESIS);
+    }
+    args.wait
+        .poll_with_timeout(
+            MSG_GENESIS_WAIT_COMPONENT,
+            wait_for_genesis_block(&client, &args.wait),
+        )
+        .await?;
+    if !args.silent {
+        logger::info(msg_genesis_block_found(&l2_rpc_url));
+    }
+    Ok(())
+}
+
+/// Polls the main node until it serves the genesis L2 block.
+async fn wait_for_genesis_block(client: &Client<L2>, args: &WaitArgs) -> anyhow::Result<()> {
+    let verbose = global_config().verbose;
+    let mut interval = tokio::time::interval(args.poll_interval());
+    interval.set_missed_tick_behavior(MissedTickBehavior::Skip);
+
+    loop {
+        interval.tick().await;
+
+        // The server may not be started yet, so connection errors are expected here.
+        match client.get_block_details(L2BlockNumber(0)).await {
+            Ok(Some(_)) => return Ok(()),
+            Ok(None) => {
+                if verbose {
+                    logger::debug(msg_wait_genesis_block_not_available(None));
+                }
+            }
+            Err(err) => {
+                if verbose {
+                    logger::debug(msg_wait_genesis_block_not_available(Some(&err)));
+                }
+            }
+        }
+    }
+}
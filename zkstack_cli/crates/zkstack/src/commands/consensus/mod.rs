@@ -404,53 +404,9 @@ impl Setup {
         }
 
         // Fetch contract state.
-        let n: usize = consensus_registry
-            .num_validators()
-            .call_raw()
-            .block(block_id)
-            .await
-            .context("num_validators()")?
-            .try_into()
-            .ok()
-            .context("num_validators() overflow")?;
-        if global_config().verbose {
-            logger::debug(format!(
-                "Fetched number of validators from consensus registry: {n}"
-            ));
-        }
-
-        multicall.block = Some(block_id);
-        let validator_owners: Vec<Address> = multicall
-            .add_calls(
-                false,
-                (0..n).map(|i| consensus_registry.validator_owners(i.into())),
-            )
-            .call_array()
-            .await
-            .context("validator_owners()")?;
-        multicall.clear_calls();
-        if global_config().verbose {
-            logger::debug(format!(
-                "Fetched validator owners from consensus registry: {validator_owners:?}"
-            ));
-        }
-
-        let validators: Vec<abi::ValidatorsReturn> = multicall
-            .add_calls(
-                false,
-                validator_owners
-                    .iter()
-                    .map(|addr| consensus_registry.validators(*addr)),
-            )
-            .call_array()
-            .await
-            .context("validators()")?;
-        multicall.clear_calls();
-        if global_config().verbose {
-            logger::debug(format!(
-                "Fetched validator info from consensus registry: {validators:?}"
-            ));
-        }
+        let (validator_owners, validators) = self
+            .fetch_validators(&consensus_registry, &mut multicall, block_id)
+            .await?;
 
         // Update the validators.
         let mut txs = TxSet::default();
@@ -558,6 +514,63 @@ impl Setup {
         Ok(())
     }
 
+    /// Fetches the owners of all validators in the registry, together with their info.
+    async fn fetch_validators<M: 'static + Middleware>(
+        &self,
+        consensus_registry: &abi::ConsensusRegistry<M>,
+        multicall: &mut Multicall<M>,
+        block_id: BlockId,
+    ) -> anyhow::Result<(Vec<Address>, Vec<abi::ValidatorsReturn>)> {
+        let n: usize = consensus_registry
+            .num_validators()
+            .call_raw()
+            .block(block_id)
+            .await
+            .context("num_validators()")?
+            .try_into()
+            .ok()
+            .context("num_validators() overflow")?;
+        if global_config().verbose {
+            logger::debug(format!(
+                "Fetched number of validators from consensus registry: {n}"
+            ));
+        }
+
+        multicall.block = Some(block_id);
+        let validator_owners: Vec<Address> = multicall
+            .add_calls(
+                false,
+                (0..n).map(|i| consensus_registry.validator_owners(i.into())),
+            )
+            .call_array()
+            .await
+            .context("validator_owners()")?;
+        multicall.clear_calls();
+        if global_config().verbose {
+            logger::debug(format!(
+                "Fetched validator owners from consensus registry: {validator_owners:?}"
+            ));
+        }
+
+        let validators: Vec<abi::ValidatorsReturn> = multicall
+            .add_calls(
+                false,
+                validator_owners
+                    .iter()
+                    .map(|addr| consensus_registry.validators(*addr)),
+            )
+            .call_array()
+            .await
+            .context("validators()")?;
+        multicall.clear_calls();
+        if global_config().verbose {
+            logger::debug(format!(
+                "Fetched validator info from consensus registry: {validators:?}"
+            ));
+        }
+        Ok((validator_owners, validators))
+    }
+
     async fn set_schedule_activation_delay(&self, delay: u64) -> anyhow::Result<()> {
         if global_config().verbose {
             logger::debug(format!(
@@ -605,6 +618,112 @@ impl Setup {
     }
 }
 
+/// Replacement of a validator key in the consensus registry.
+///
+/// The registry is checked in [`Self::prepare()`], so that a key rotation can fail
+/// before anything is written locally.
+pub(crate) struct RegistryKeyRotation {
+    setup: Setup,
+    validator_owner: Address,
+    validator: abi::ValidatorAttr,
+}
+
+impl RegistryKeyRotation {
+    /// Finds the validator with `old_key` in the registry.
+    pub(crate) async fn prepare(
+        shell: &Shell,
+        old_key: &validator::PublicKey,
+    ) -> anyhow::Result<Self> {
+        let setup = Setup::new(shell).await?;
+        let provider = Arc::new(setup.provider().context("provider()")?);
+        let block_id = setup.last_block(&provider).await.context("last_block()")?;
+        let consensus_registry = setup
+            .consensus_registry(provider.clone())
+            .context("consensus_registry()")?;
+        let mut multicall = setup.multicall(provider).context("multicall()")?;
+        let (validator_owners, validators) = setup
+            .fetch_validators(&consensus_registry, &mut multicall, block_id)
+            .await?;
+
+        let (validator_owner, validator) = validator_owners
+            .into_iter()
+            .zip(validators)
+            .map(|(owner, validator)| (owner, validator.latest))
+            .filter(|(_, validator)| !validator.removed)
+            .find(|(_, validator)| {
+                decode_validator_key(&validator.pub_key).is_ok_and(|key| &key == old_key)
+            })
+            .context(messages::MSG_VALIDATOR_KEY_NOT_IN_REGISTRY_ERR)?;
+        Ok(Self {
+            setup,
+            validator_owner,
+            validator,
+        })
+    }
+
+    /// Removes the old validator and adds `new_key` with the same weight, leadership and activity,
+    /// then commits the validator committee.
+    pub(crate) async fn commit(self, new_key: &validator::SecretKey) -> anyhow::Result<()> {
+        let Self {
+            setup,
+            validator_owner,
+            validator,
+        } = self;
+        let provider = setup.provider().context("provider()")?;
+        let governor = setup.governor().context("governor()")?;
+        let signer = setup.signer(
+            governor
+                .private_key
+                .clone()
+                .context(messages::MSG_GOVERNOR_PRIVATE_KEY_NOT_SET)?,
+        )?;
+        let consensus_registry = setup
+            .consensus_registry(signer)
+            .context("consensus_registry()")?;
+
+        let owner = consensus_registry.owner().call().await.context("owner()")?;
+        if owner != governor.address {
+            anyhow::bail!(
+                "governor ({:#x}) is different than the consensus registry owner ({:#x})",
+                governor.address,
+                owner
+            );
+        }
+
+        let mut txs = TxSet::default();
+        txs.send(
+            format!("remove({validator_owner:?})"),
+            consensus_registry.remove(validator_owner),
+        )
+        .await?;
+        let new_owner = Address::random();
+        txs.send(
+            format!("add({:?}, {})", new_key.public(), validator.weight),
+            consensus_registry.add(
+                new_owner,
+                validator.leader,
+                validator.weight,
+                encode_validator_key(&new_key.public()),
+                encode_validator_pop(&new_key.sign_pop()),
+            ),
+        )
+        .await?;
+        if !validator.active {
+            txs.send(
+                format!("deactivate({new_owner:?})"),
+                consensus_registry.change_validator_active(new_owner, false),
+            )
+            .await?;
+        }
+        txs.send(
+            "commit_validator_committee".to_owned(),
+            consensus_registry.commit_validator_committee(),
+        )
+        .await?;
+        txs.wait(&provider).await.context("wait()")
+    }
+}
+
 /// Collection of sent transactions.
 #[derive(Default)]
 struct TxSet(Vec<(H256, String)>);
@@ -6,7 +6,7 @@ use slugify_rs::slugify;
 use strum::IntoEnumIterator;
 use xshell::Shell;
 use zkstack_cli_common::{Prompt, PromptConfirm, PromptSelect};
-use zkstack_cli_types::{L1Network, WalletCreation};
+use zkstack_cli_types::{L1Network, L1NetworkValueParser, WalletCreation};
 
 use crate::{
     commands::chain::{args::create::ChainCreateArgs, ChainCreateArgsFinal},
@@ -21,7 +21,7 @@ use crate::{
 pub struct EcosystemCreateArgs {
     #[arg(long)]
     pub ecosystem_name: Option<String>,
-    #[clap(long, help = MSG_L1_NETWORK_HELP, value_enum)]
+    #[clap(long, help = MSG_L1_NETWORK_HELP, value_parser = L1NetworkValueParser)]
     pub l1_network: Option<L1Network>,
     #[clap(long, help = MSG_LINK_TO_CODE_HELP, value_hint = ValueHint::DirPath)]
     pub link_to_code: Option<PathBuf>,
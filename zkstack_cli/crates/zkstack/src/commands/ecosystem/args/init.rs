@@ -8,13 +8,17 @@ use zkstack_cli_types::{L1Network, VMOption};
 
 use crate::{
     commands::{
-        chain::args::{genesis::GenesisArgs, init::da_configs::ValidiumTypeArgs},
+        chain::args::{
+            genesis::GenesisArgs,
+            init::{da_configs::ValidiumTypeArgs, NetworkCheckArgs},
+        },
         ecosystem::args::common::CommonEcosystemArgs,
     },
     messages::{
         MSG_BRIDGEHUB, MSG_DEPLOY_ECOSYSTEM_PROMPT, MSG_DEPLOY_ERC20_PROMPT, MSG_DEV_ARG_HELP,
         MSG_NO_PORT_REALLOCATION_HELP, MSG_OBSERVABILITY_HELP, MSG_OBSERVABILITY_PROMPT,
         MSG_SERVER_COMMAND_HELP, MSG_SERVER_DB_NAME_HELP, MSG_SERVER_DB_URL_HELP,
+        MSG_SKIP_L1_CHECK_HELP,
     },
 };
 
@@ -66,6 +70,11 @@ pub struct EcosystemInitArgs {
     pub server_command: Option<String>,
     #[clap(long, help = MSG_BRIDGEHUB)]
     pub no_genesis: bool,
+    #[clap(long, help = MSG_SKIP_L1_CHECK_HELP)]
+    pub skip_l1_check: bool,
+    #[clap(flatten)]
+    #[serde(flatten)]
+    pub network_check: NetworkCheckArgs,
 }
 
 impl EcosystemInitArgs {
@@ -79,6 +88,7 @@ impl EcosystemInitArgs {
                 dev: self.dev,
                 dont_drop: self.dont_drop,
                 server_command: self.server_command.clone(),
+                skip_l1_check: self.skip_l1_check,
             })
         }
     }
@@ -102,6 +112,8 @@ impl EcosystemInitArgs {
             skip_priority_txs,
             deploy_paymaster,
             ecosystem_contracts_path,
+            skip_l1_check,
+            network_check,
             ..
         } = self;
 
@@ -152,6 +164,8 @@ impl EcosystemInitArgs {
             vm_option: common.vm_option,
             ecosystem_contracts_path,
             l1_rpc_url: common.l1_rpc_url,
+            skip_l1_check,
+            network_check,
         })
     }
 }
@@ -174,6 +188,8 @@ pub struct EcosystemInitArgsFinal {
     pub skip_priority_txs: bool,
     pub genesis_args: Option<GenesisArgs>,
     pub vm_option: VMOption,
+    pub skip_l1_check: bool,
+    pub network_check: NetworkCheckArgs,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, Parser)]
@@ -204,8 +204,10 @@ pub async fn init_chains(
             make_permanent_rollup: args.make_permanent_rollup,
             no_genesis: genesis_args.is_none(),
             skip_priority_txs: args.skip_priority_txs,
+            skip_l1_check: args.skip_l1_check,
+            network_check: args.network_check.clone(),
         };
-        let final_chain_init_args = chain_init_args.fill_values_with_prompt(&chain_config);
+        let final_chain_init_args = chain_init_args.fill_values_with_prompt(&chain_config).await;
 
         chain::init::init(
             &final_chain_init_args,
@@ -246,9 +246,7 @@ async fn return_ecosystem_contracts(
             L1Network::Localhost => {
                 ContractsConfig::get_path_with_base_path(&ecosystem_config.config)
             }
-            L1Network::Sepolia | L1Network::Holesky | L1Network::Mainnet => {
-                ecosystem_preexisting_configs_path
-            }
+            _ => ecosystem_preexisting_configs_path,
         });
 
     // We don't have a zksync os preexisting contracts config, so we can assume
@@ -30,8 +30,8 @@ pub struct PrepareConfigArgs {
 }
 
 impl PrepareConfigArgs {
-    pub fn fill_values_with_prompt(self, config: &ChainConfig) -> PrepareConfigFinal {
-        let db_name = generate_external_node_db_name(config);
+    pub async fn fill_values_with_prompt(self, config: &ChainConfig) -> PrepareConfigFinal {
+        let db_name = generate_external_node_db_name(config).await;
         let chain_name = config.name.clone();
         if self.use_default {
             PrepareConfigFinal {
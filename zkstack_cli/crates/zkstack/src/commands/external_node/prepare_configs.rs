@@ -24,7 +24,7 @@ pub async fn run(shell: &Shell, args: PrepareConfigArgs) -> anyhow::Result<()> {
     logger::info(MSG_PREPARING_EN_CONFIGS);
     let mut chain_config = ZkStackConfig::current_chain(shell)?;
 
-    let args = args.fill_values_with_prompt(&chain_config);
+    let args = args.fill_values_with_prompt(&chain_config).await;
     let external_node_config_path = chain_config
         .external_node_config_path
         .unwrap_or_else(|| chain_config.configs.join("external_node"));
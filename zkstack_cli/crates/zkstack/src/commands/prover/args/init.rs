@@ -155,7 +155,7 @@ pub(crate) struct ProverInitArgsFinal {
 }
 
 impl ProverInitArgs {
-    pub(crate) fn fill_values_with_prompt(
+    pub(crate) async fn fill_values_with_prompt(
         &self,
         shell: &Shell,
         default_compressor_key_path: &Path,
@@ -166,7 +166,7 @@ impl ProverInitArgs {
         let compressor_key_args =
             self.fill_setup_compressor_key_values_with_prompt(default_compressor_key_path);
         let bellman_cuda_config = self.fill_bellman_cuda_values_with_prompt();
-        let database_config = self.fill_database_values_with_prompt(chain_config);
+        let database_config = self.fill_database_values_with_prompt(chain_config).await;
         let setup_keys = self.fill_setup_keys_values_with_prompt();
 
         Ok(ProverInitArgsFinal {
@@ -408,7 +408,7 @@ impl ProverInitArgs {
         }
     }
 
-    fn fill_database_values_with_prompt(
+    async fn fill_database_values_with_prompt(
         &self,
         config: &ChainConfig,
     ) -> Option<ProverDatabaseConfig> {
@@ -418,7 +418,7 @@ impl ProverInitArgs {
                 .unwrap_or_else(|| PromptConfirm::new("Do you want to setup the database?").ask());
 
         if setup_database {
-            let DBNames { prover_name, .. } = generate_db_names(config);
+            let DBNames { prover_name, .. } = generate_db_names(config).await;
             let chain_name = config.name.clone();
 
             let dont_drop = if !self.dev {
@@ -40,7 +40,9 @@ pub(crate) async fn run(args: ProverInitArgs, shell: &Shell) -> anyhow::Result<(
     let default_compressor_key_path =
         get_default_compressor_keys_path(&chain_config.link_to_code())?;
 
-    let args = args.fill_values_with_prompt(shell, &default_compressor_key_path, &chain_config)?;
+    let args = args
+        .fill_values_with_prompt(shell, &default_compressor_key_path, &chain_config)
+        .await?;
 
     if chain_config.get_general_config().await.is_err()
         || chain_config.get_secrets_config().await.is_err()
@@ -1,6 +1,5 @@
 pub const AMOUNT_FOR_DISTRIBUTION_TO_WALLETS: u128 = 2_000u128 * 1_000_000_000_000_000_000u128;
 
-pub const MINIMUM_BALANCE_FOR_WALLET: u128 = 5u128 * 1_000_000_000_000_000_000u128;
 /// The default block range within which we search for events within one query.
 pub const DEFAULT_EVENTS_BLOCK_RANGE: u64 = 50_000;
 pub const SERVER_MIGRATIONS: &str = "core/lib/dal/migrations";
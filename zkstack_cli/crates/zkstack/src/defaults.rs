@@ -1,6 +1,8 @@
 use lazy_static::lazy_static;
 use url::Url;
-use zkstack_cli_config::ChainConfig;
+use zkstack_cli_common::db::DatabaseConfig;
+use zkstack_cli_config::{ChainConfig, SecretsConfig};
+use zkstack_cli_types::L1Network;
 
 lazy_static! {
     pub static ref DATABASE_SERVER_URL: Url =
@@ -39,41 +41,140 @@ pub struct DBNames {
     pub prover_name: String,
 }
 
-pub fn generate_db_names(config: &ChainConfig) -> DBNames {
+/// PostgreSQL truncates identifiers longer than this many bytes.
+const MAX_DB_IDENTIFIER_LEN: usize = 63;
+
+/// Returns the default server and prover database names for `config`.
+///
+/// Chains whose secrets already point to the databases named by older versions, which did
+/// not sanitize the chain name, keep using them.
+pub async fn generate_db_names(config: &ChainConfig) -> DBNames {
+    let secrets = config.get_secrets_config().await.ok();
+    let configured_server = secrets
+        .as_ref()
+        .and_then(|secrets| configured_db_name(secrets.core_database_url()));
+    let configured_prover = secrets
+        .as_ref()
+        .and_then(|secrets| configured_db_name(secrets.prover_database_url()));
     DBNames {
-        server_name: format!(
-            "zksync_server_{}_{}",
-            config.l1_network.to_string().to_ascii_lowercase(),
-            config.name
-        ),
-        prover_name: format!(
-            "zksync_prover_{}_{}",
-            config.l1_network.to_string().to_ascii_lowercase(),
-            config.name
-        ),
+        server_name: db_name_or_legacy("zksync_server", config, configured_server.as_deref()),
+        prover_name: db_name_or_legacy("zksync_prover", config, configured_prover.as_deref()),
     }
 }
 
 pub fn generate_private_rpc_db_name(config: &ChainConfig) -> String {
-    format!(
-        "zksync_private_rpc_{}_{}",
-        config.l1_network.to_string().to_ascii_lowercase(),
-        config.name
-    )
+    generate_db_name("zksync_private_rpc", config)
 }
 
 pub fn generate_explorer_db_name(config: &ChainConfig) -> String {
-    format!(
-        "zksync_explorer_{}_{}",
-        config.l1_network.to_string().to_ascii_lowercase(),
-        config.name
-    )
+    generate_db_name("zksync_explorer", config)
+}
+
+/// Returns the default external node database name for `config`, keeping the legacy one
+/// like [`generate_db_names`] does.
+pub async fn generate_external_node_db_name(config: &ChainConfig) -> String {
+    let configured = config
+        .get_external_node_secrets_config()
+        .await
+        .ok()
+        .and_then(|secrets: SecretsConfig| configured_db_name(secrets.core_database_url()));
+    db_name_or_legacy("external_node", config, configured.as_deref())
+}
+
+fn configured_db_name(url: anyhow::Result<Option<Url>>) -> Option<String> {
+    let url = url.ok()??;
+    Some(DatabaseConfig::from_url(&url).ok()?.name)
+}
+
+fn db_name_or_legacy(prefix: &str, config: &ChainConfig, configured: Option<&str>) -> String {
+    keep_legacy_db_name(prefix, config.l1_network, &config.name, configured)
+}
+
+fn keep_legacy_db_name(
+    prefix: &str,
+    l1_network: L1Network,
+    chain_name: &str,
+    configured: Option<&str>,
+) -> String {
+    let legacy_name = format!(
+        "{prefix}_{}_{chain_name}",
+        l1_network.to_string().to_ascii_lowercase()
+    );
+    match configured {
+        Some(configured) if configured == legacy_name => legacy_name,
+        _ => db_name(prefix, l1_network, chain_name),
+    }
+}
+
+fn generate_db_name(prefix: &str, config: &ChainConfig) -> String {
+    db_name(prefix, config.l1_network, &config.name)
 }
 
-pub fn generate_external_node_db_name(config: &ChainConfig) -> String {
-    format!(
-        "external_node_{}_{}",
-        config.l1_network.to_string().to_ascii_lowercase(),
-        config.name
-    )
+fn db_name(prefix: &str, l1_network: L1Network, chain_name: &str) -> String {
+    sanitize_db_identifier(&format!("{prefix}_{l1_network}_{chain_name}"))
+}
+
+/// Turns `s` into a valid unquoted PostgreSQL identifier: lowercase ASCII letters, digits and
+/// underscores, not starting with a digit and at most 63 characters long.
+pub fn sanitize_db_identifier(s: &str) -> String {
+    let mut identifier: String = s
+        .chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() {
+                c.to_ascii_lowercase()
+            } else {
+                '_'
+            }
+        })
+        .collect();
+    if identifier.is_empty() || identifier.starts_with(|c: char| c.is_ascii_digit()) {
+        identifier.insert(0, '_');
+    }
+    identifier.truncate(MAX_DB_IDENTIFIER_LEN);
+    identifier
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sanitize_db_identifier() {
+        assert_eq!(sanitize_db_identifier("era"), "era");
+        assert_eq!(sanitize_db_identifier("My-Chain!2"), "my_chain_2");
+        assert_eq!(sanitize_db_identifier("2chain"), "_2chain");
+        assert_eq!(sanitize_db_identifier(""), "_");
+        assert_eq!(sanitize_db_identifier("chain ü"), "chain__");
+        assert_eq!(
+            sanitize_db_identifier(&"a".repeat(100)).len(),
+            MAX_DB_IDENTIFIER_LEN
+        );
+    }
+
+    #[test]
+    fn test_db_name() {
+        assert_eq!(
+            db_name("zksync_server", L1Network::Sepolia, "My-Chain"),
+            "zksync_server_sepolia_my_chain"
+        );
+        // Only the full name must not start with a digit.
+        assert_eq!(
+            db_name("zksync_server", L1Network::Localhost, "2chain"),
+            "zksync_server_localhost_2chain"
+        );
+    }
+
+    #[test]
+    fn test_keep_legacy_db_name() {
+        let name = |configured| {
+            keep_legacy_db_name("zksync_server", L1Network::Sepolia, "MyChain", configured)
+        };
+
+        assert_eq!(name(None), "zksync_server_sepolia_mychain");
+        assert_eq!(
+            name(Some("zksync_server_sepolia_MyChain")),
+            "zksync_server_sepolia_MyChain"
+        );
+        assert_eq!(name(Some("zksync_local")), "zksync_server_sepolia_mychain");
+    }
 }
@@ -9,6 +9,7 @@ use ethers::{
     utils::format_ether,
 };
 use url::Url;
+use zkstack_cli_types::L1Network;
 use zksync_consensus_roles::validator;
 
 use crate::utils::forge::WalletOwner;
@@ -33,7 +34,7 @@ pub(super) const MSG_OUTRO_AUTOCOMPLETE_GENERATION: &str =
     "Autocompletion file correctly generated";
 
 /// Ecosystem create related messages
-pub(super) const MSG_L1_NETWORK_HELP: &str = "L1 Network";
+pub(super) const MSG_L1_NETWORK_HELP: &str = "L1 Network, by name (case-insensitive) or chain ID";
 pub(super) const MSG_LINK_TO_CODE_HELP: &str = "Code link";
 pub(super) const MSG_START_CONTAINERS_HELP: &str =
     "Start reth and postgres containers after creation";
@@ -107,8 +108,48 @@ pub(super) const MSG_CHAIN_INITIALIZED: &str = "Chain initialized successfully";
 pub(super) const MSG_CHAIN_CONFIGS_INITIALIZED: &str = "Chain configs were initialized";
 pub(super) const MSG_CHAIN_OWNERSHIP_TRANSFERRED: &str =
     "Chain ownership was transferred successfully";
+pub(super) const MSG_CHECKING_PENDING_ADMIN_SPINNER: &str = "Checking pending chain admin...";
+pub(super) const MSG_CHAIN_ADMIN_ALREADY_ACCEPTED: &str =
+    "Chain admin is already accepted, skipping accept-chain-ownership";
+pub(super) const MSG_NO_PENDING_ADMIN: &str =
+    "The chain has no pending admin, skipping accept-chain-ownership";
+
+pub(super) fn msg_pending_admin_mismatch(pending_admin: Address, chain_admin: Address) -> String {
+    format!(
+        "Pending admin of the chain is {pending_admin:?}, not the chain admin {chain_admin:?} \
+        from the contracts config, skipping accept-chain-ownership"
+    )
+}
+
+pub(super) fn msg_chain_admin_accepted_cached(path: &Path) -> String {
+    format!(
+        "Chain admin is already accepted according to {}, skipping accept-chain-ownership. \
+        Remove the file to check the chain again",
+        path.display()
+    )
+}
 pub(super) const MSG_EVM_EMULATOR_ENABLED: &str = "EVM emulator enabled successfully";
 pub(super) const MSG_CHAIN_REGISTERED: &str = "Chain registraion was successful";
+pub(super) const MSG_CHAIN_ALREADY_REGISTERED: &str =
+    "Chain is already registered in the bridgehub, skipping the registration";
+pub(super) const MSG_CHAIN_NOT_REGISTERED: &str =
+    "Chain is not registered in the bridgehub, registering it";
+pub(super) const MSG_NO_CHAIN_CONTRACTS_CONFIG_ERR: &str =
+    "Failed to read the chain contracts config, cannot check whether the chain is registered";
+
+pub(super) fn msg_registered_diamond_proxy_mismatch(
+    registered: Address,
+    expected: Address,
+) -> String {
+    format!(
+        "Bridgehub has diamond proxy {registered:?} registered for the chain, \
+        but the chain contracts config has {expected:?}"
+    )
+}
+
+pub(super) fn msg_contracts_without_code(contracts: &[String]) -> String {
+    format!("Contracts have no code on L1: {}", contracts.join(", "))
+}
 pub(super) const MSG_DISTRIBUTING_ETH_SPINNER: &str = "Distributing eth...";
 pub(super) const MSG_MINT_BASE_TOKEN_SPINNER: &str =
     "Minting base token to the governance addresses...";
@@ -128,6 +169,111 @@ pub(super) const MSG_UPDATING_DA_VALIDATOR_PAIR_SPINNER: &str = "Updating da val
 pub(super) const MSG_TOKEN_MULTIPLIER_SETTER_UPDATED_TO: &str =
     "Token multiplier setter updated to";
 pub(super) const MSG_DA_VALIDATOR_PAIR_UPDATED_TO: &str = "DA validator pair updated to";
+pub(super) const MSG_DEPLOYING_L2_DA_VALIDATOR_SPINNER: &str = "Deploying L2 DA validator...";
+
+pub(super) fn msg_da_validator_pair_upgraded(
+    l1_da_validator: Address,
+    l2_da_validator: Address,
+) -> String {
+    format!("DA validator pair upgraded to L1: {l1_da_validator:?}, L2: {l2_da_validator:?}")
+}
+pub(super) const MSG_NO_FORGE_HELP: &str =
+    "Encode the admin calldata directly instead of running the forge script";
+pub(super) const MSG_SUBMIT_HELP: &str =
+    "Send the encoded calldata to the chain admin using the governor wallet";
+pub(super) const MSG_DA_VALIDATOR_PAIR_ZERO_ADDRESS_ERR: &str =
+    "DA validator addresses must not be zero";
+pub(super) const MSG_DA_VALIDATOR_PAIR_SAME_ADDRESS_ERR: &str =
+    "L1 and L2 DA validator addresses must be different";
+pub(super) const MSG_VERIFYING_TRANSACTION_FILTERER_SPINNER: &str =
+    "Verifying transaction filterer...";
+pub(super) const MSG_TRANSACTION_FILTERER_CALL_FAILED_ERR: &str =
+    "Calling isTransactionAllowed on the transaction filterer failed";
+pub(super) const MSG_TRANSACTION_FILTERER_INVALID_OUTPUT_ERR: &str =
+    "Transaction filterer returned an invalid isTransactionAllowed result";
+pub(super) const MSG_TRANSACTION_FILTERER_INTERFACE_NOT_SUPPORTED_ERR: &str =
+    "Transaction filterer does not support the ITransactionFilterer interface";
+pub(super) const MSG_TRANSACTION_FILTERER_UNSET: &str =
+    "Transaction filterer is the zero address, it will be removed";
+pub(super) const MSG_TRANSACTION_FILTERER_FORCE_HINT: &str =
+    "Transaction filterer does not look like an ITransactionFilterer, pass --force to set it anyway";
+
+pub(super) fn msg_transaction_filterer_no_code(address: Address) -> String {
+    format!("Transaction filterer {address:?} has no contract code on L1")
+}
+
+pub(super) const MSG_ADMIN_BATCH_EMPTY_ERR: &str =
+    "No admin operations requested, pass at least one of --transaction-filterer, --l1-da-validator/--l2-da-validator or --pubdata-pricing-mode";
+pub(super) const MSG_ADMIN_BATCH_NOT_REGISTERED_ERR: &str =
+    "The chain is not registered in the bridgehub";
+pub(super) const MSG_ADMIN_BATCH_CHAIN_NOT_ON_L1_ERR: &str =
+    "The chain does not settle on L1, admin batches are only supported for L1 settled chains";
+pub(super) const MSG_SKIP_L1_CHECK_HELP: &str =
+    "Skip the L1 RPC checks: the chain ID matching the chain's L1 network and the base token being deployed";
+pub(super) const MSG_CHECKING_L1_CHAIN_ID_SPINNER: &str = "Checking L1 chain ID...";
+pub(super) const MSG_FETCHING_L1_CHAIN_ID_ERR: &str = "Failed to fetch chain ID from the L1 RPC";
+
+pub(super) fn msg_l1_chain_id_mismatch(
+    l1_network: L1Network,
+    expected: u64,
+    actual: u64,
+) -> String {
+    format!(
+        "L1 RPC returned chain ID {actual}, but the chain is registered on {l1_network} \
+        (chain ID {expected}). Check the L1 RPC URL or pass --skip-l1-check"
+    )
+}
+
+pub(super) const MSG_MAX_BLOCK_AGE_SECS_HELP: &str =
+    "Maximum age of the latest L1 block in seconds before L1 is considered stalled. \
+    Defaults to 5 block times of the chain's L1 network, but at least 30 seconds";
+pub(super) const MSG_SKIP_NETWORK_CHECK_HELP: &str =
+    "Skip checking that the L1 RPC serves recent blocks";
+pub(super) const MSG_CHECKING_L1_BLOCK_AGE_SPINNER: &str = "Checking latest L1 block...";
+pub(super) const MSG_FETCHING_L1_LATEST_BLOCK_ERR: &str =
+    "Failed to fetch the latest block from the L1 RPC";
+
+pub(super) fn msg_l1_block_too_old(block_age: u64, max_block_age: Duration) -> String {
+    format!(
+        "Latest L1 block is {block_age}s old, more than the allowed {}s. \
+        The L1 RPC may be stalled or out of sync; pass --skip-network-check to ignore",
+        max_block_age.as_secs()
+    )
+}
+
+pub(super) fn msg_avail_l1_da_validator_not_set(l1_network: L1Network) -> String {
+    format!(
+        "Avail L1 DA validator address is not set in the contracts config and there is no builtin \
+        one for {l1_network}. Set `avail_l1_da_validator_addr` in the ecosystem's initial \
+        deployments config and redeploy the ecosystem contracts"
+    )
+}
+pub(super) const MSG_CHAIN_CONFIG_VALIDATION_FAILED_ERR: &str =
+    "Chain config validation failed, fix the errors above before running chain init";
+pub(super) const MSG_ROLLUP_CUSTOM_PUBDATA_SENDING_MODE_ERR: &str =
+    "Pubdata sending mode CUSTOM in general.yaml does not publish pubdata to L1, \
+    but the chain is a Rollup";
+
+pub(super) fn msg_port_conflict(port: u16, description: &str, used_by: &str) -> String {
+    format!("Port {port} ({description}) is already used by {used_by}. Drop --no-port-reallocation to reallocate it")
+}
+
+pub(super) fn msg_base_token_not_deployed(address: Address) -> String {
+    format!("Base token {address:?} has no contract code on L1")
+}
+
+pub(super) fn msg_base_token_check_failed(address: Address, err: impl fmt::Display) -> String {
+    format!("Failed to check base token {address:?} on L1: {err}")
+}
+
+pub(super) fn msg_pubdata_sending_mode_mismatch(mode: &str, run_genesis: bool) -> String {
+    let hint = if run_genesis {
+        "it will be switched to CUSTOM by the validium override during genesis"
+    } else {
+        "run chain genesis to apply the validium override"
+    };
+    format!("Pubdata sending mode {mode} in general.yaml does not match the Validium commitment mode, {hint}")
+}
 pub(super) const MSG_GOT_SETTLEMENT_LAYER_ADDRESS_FROM_GW: &str =
     "Got the settlement layer address from gateway";
 pub(super) const MSG_USE_GATEWAY_HELP: &str = "Use the Gateway to set the DA validator pair";
@@ -136,6 +282,12 @@ pub(super) const MSG_GATEWAY_URL_MUST_BE_PRESET: &str =
 pub(super) const MSG_UPDATING_PUBDATA_PRICING_MODE_SPINNER: &str =
     "Updating pubdata pricing mode...";
 pub(super) const MSG_PUBDATA_PRICING_MODE_UPDATED_TO: &str = "Pubdata pricing mode updated to";
+pub(super) const MSG_CHECKING_CURRENT_PUBDATA_PRICING_MODE_SPINNER: &str =
+    "Checking current pubdata pricing mode...";
+
+pub(super) fn msg_pubdata_pricing_mode_already_set(mode: &str) -> String {
+    format!("Pubdata pricing mode is already set to {mode}, skipping the transaction")
+}
 pub(super) const MSG_RECREATE_ROCKS_DB_ERRROR: &str = "Failed to create rocks db path";
 pub(super) const MSG_ERA_OBSERVABILITY_ALREADY_SETUP: &str = "Era observability already setup";
 pub(super) const MSG_DOWNLOADING_ERA_OBSERVABILITY_SPINNER: &str =
@@ -248,6 +400,19 @@ pub(super) const MSG_FAILED_TO_DROP_PROVER_DATABASE_ERR: &str = "Failed to drop
 pub(super) const MSG_GENESIS_DATABASES_INITIALIZED: &str = "Databases initialized successfully";
 pub(super) const MSG_BRIDGEHUB: &str = "Bridgehub address of existing ecosystem";
 pub(super) const MSG_CTM: &str = "Chain type manager address that has to be registered";
+pub(super) const MSG_WAITING_FOR_GENESIS: &str = "Waiting for the genesis L2 block to be served";
+pub(super) const MSG_GENESIS_WAIT_COMPONENT: &str = "genesis L2 block";
+
+pub(super) fn msg_genesis_block_found(l2_rpc_url: &str) -> String {
+    format!("Genesis L2 block is available on {l2_rpc_url}")
+}
+
+pub(super) fn msg_wait_genesis_block_not_available(err: Option<&dyn fmt::Display>) -> String {
+    match err {
+        Some(err) => format!("Genesis L2 block is not available yet: {err}"),
+        None => "Genesis L2 block is not available yet".to_owned(),
+    }
+}
 
 /// Chain update related messages
 pub(super) const MSG_WALLETS_CONFIG_MUST_BE_PRESENT: &str = "Wallets configuration must be present";
@@ -284,6 +449,13 @@ pub(super) fn msg_explorer_db_name_prompt(chain_name: &str) -> String {
     format!("Please provide explorer database name for chain {chain_name}")
 }
 
+/// Chain fee config related messages
+pub(super) fn msg_fee_config_for_chain(chain_name: &str, l1_network: L1Network) -> String {
+    format!("Fee config of chain {chain_name} (L1 network: {l1_network})")
+}
+pub(super) const MSG_FEE_CONFIG_MODIFIED_NOTE: &str =
+    "Params marked with `*` differ from the default config template";
+
 /// Chain initialize bridges related messages
 pub(super) const MSG_DEPLOYING_L2_CONTRACT_SPINNER: &str = "Deploying l2 contracts";
 
@@ -297,6 +469,14 @@ pub(super) const MSG_CHAIN_TXN_OUT_PATH_INVALID_ERR: &str = "Invalid path";
 pub(super) const MSG_CHAIN_TXN_MISSING_CONTRACT_CONFIG: &str =
     "Missing contract.yaml, please be sure to run this command within initialized ecosystem";
 pub(super) const MSG_CHAIN_TRANSACTIONS_BUILT: &str = "Chain transactions successfully built";
+pub(super) fn msg_safe_batch_skipped_contract_creations(contracts: &[String]) -> String {
+    format!(
+        "A Safe batch can't deploy contracts, skipped {} contract creation transaction(s): {}. \
+        Deploy them from the deployer wallet, e.g. with the forge output format",
+        contracts.len(),
+        contracts.join(", ")
+    )
+}
 
 /// Run server related messages
 pub(super) const MSG_SERVER_COMPONENTS_HELP: &str = "Components of server to run";
@@ -432,16 +612,60 @@ pub(super) fn msg_wallet_private_key_not_set(wallet_owner: WalletOwner) -> Strin
 
 pub(super) fn msg_address_doesnt_have_enough_money_prompt(
     address: &H160,
+    l1_network: Option<L1Network>,
+    l1_chain_id: u64,
     actual: U256,
     expected: U256,
 ) -> String {
     let actual = format_ether(actual);
     let expected = format_ether(expected);
+    let network = match l1_network {
+        Some(l1_network) => l1_network.to_string(),
+        None => format!("an unknown network (chain ID {l1_chain_id})"),
+    };
+    format!(
+        "It is recommended to have {expected} ETH on the address {address:?} to deploy contracts on {network} (use --min-balance to change the threshold). Current balance is {actual} ETH. How do you want to proceed?",
+    )
+}
+
+pub(super) fn msg_testnet_funding_hint(address: &H160, l1_network: L1Network) -> String {
+    match l1_network {
+        L1Network::Localhost => {
+            format!("Fund {address:?} from one of the rich wallets of the local L1 node")
+        }
+        _ => format!("{l1_network} is a testnet, fund {address:?} from a {l1_network} faucet"),
+    }
+}
+
+pub(super) fn msg_insufficient_balance_err(
+    address: &H160,
+    l1_network: Option<L1Network>,
+    l1_chain_id: u64,
+    actual: U256,
+    expected: U256,
+) -> String {
+    let network = l1_network.map_or_else(|| "unknown".to_owned(), |network| network.to_string());
     format!(
-        "It is recommended to have {expected} ETH on the address {address:?} to deploy contracts. Current balance is {actual} ETH. How do you want to proceed?",
+        "Insufficient balance: address={address:?} network={network} chain_id={l1_chain_id} balance_wei={actual} required_wei={expected}"
     )
 }
 
+pub(super) fn msg_insufficient_balance_ignored(
+    address: &H160,
+    actual: U256,
+    expected: U256,
+) -> String {
+    let actual = format_ether(actual);
+    let expected = format_ether(expected);
+    format!(
+        "Balance of {address:?} is {actual} ETH, which is below the recommended {expected} ETH. Proceeding because of --ignore-balance-check"
+    )
+}
+
+pub(super) fn msg_invalid_min_balance_env(env_var: &str) -> String {
+    format!("Invalid {env_var} value, expected an amount in ETH")
+}
+
 pub(super) fn msg_preparing_en_config_is_done(path: &Path) -> String {
     format!("External nodes configs could be found in: {path:?}")
 }
@@ -648,6 +872,46 @@ pub(super) fn msg_wait_consensus_registry_started_polling(addr: Address, url: &U
     format!("Starting polling L2 HTTP RPC at {url} for code at {addr:?}")
 }
 
+pub(super) const MSG_ROTATE_CONSENSUS_KEY_HELP: &str =
+    "Instead of deploying the registry, replace the main node validator key with a newly generated \
+    one in the configs and in the deployed registry. The previous secrets config is backed up";
+
+pub(super) fn msg_secrets_backed_up(path: &Path) -> String {
+    format!("Secrets config backed up to {}", path.display())
+}
+
+pub(super) fn msg_secrets_backup_exists(path: &Path) -> String {
+    format!(
+        "Secrets backup {} already exists, retry in a second",
+        path.display()
+    )
+}
+
+pub(super) const MSG_VALIDATOR_KEY_NOT_IN_GENESIS_SPEC_ERR: &str =
+    "The current validator key is not in the consensus genesis spec of the general config";
+
+pub(super) fn msg_rotated_key_not_published(validator_public: &str, backup_path: &Path) -> String {
+    format!(
+        "Secrets already contain the new validator key, but the genesis spec was not updated. \
+        Add {validator_public} to the general config by hand, or restore the secrets from {}",
+        backup_path.display()
+    )
+}
+
+pub(super) const MSG_VALIDATOR_KEY_NOT_IN_REGISTRY_ERR: &str =
+    "The current validator key is not registered in the consensus registry";
+
+pub(super) fn msg_rotated_key_not_registered(old_public: &str, new_public: &str) -> String {
+    format!(
+        "The configs already use the new validator key, but the consensus registry was not updated. \
+        Replace {old_public} with {new_public} in the registry with `zkstack consensus set-validator-schedule`"
+    )
+}
+
+pub(super) fn msg_consensus_keys_rotated(validator_public: &str) -> String {
+    format!("Validator key rotated, new public key: {validator_public}")
+}
+
 pub(super) fn msg_consensus_registry_wait_success(addr: Address, code_len: usize) -> String {
     format!("Consensus registry is deployed at {addr:?}: {code_len} bytes")
 }
@@ -1,12 +1,19 @@
 use anyhow::Context as _;
-use ethers::types::U256;
-use zkstack_cli_common::{forge::ForgeScript, wallets::Wallet};
+use ethers::{types::U256, utils::parse_ether};
+use zkstack_cli_common::{forge::ForgeScript, logger, wallets::Wallet};
+use zkstack_cli_types::L1Network;
 
-use crate::{
-    consts::MINIMUM_BALANCE_FOR_WALLET,
-    messages::{msg_address_doesnt_have_enough_money_prompt, msg_wallet_private_key_not_set},
+use crate::messages::{
+    msg_address_doesnt_have_enough_money_prompt, msg_insufficient_balance_err,
+    msg_insufficient_balance_ignored, msg_invalid_min_balance_env, msg_testnet_funding_hint,
+    msg_wallet_private_key_not_set,
 };
 
+/// Environment variable overriding the minimum deployer balance (in ETH), e.g. for private networks.
+const MIN_BALANCE_ENV: &str = "ZKSTACK_MIN_BALANCE";
+/// Environment variable enabling non-interactive mode (same as `--non-interactive`), e.g. for CI.
+const NON_INTERACTIVE_ENV: &str = "ZKSTACK_NON_INTERACTIVE";
+
 pub enum WalletOwner {
     Governor,
     Deployer,
@@ -36,14 +43,56 @@ pub async fn check_the_balance(forge: &ForgeScript) -> anyhow::Result<()> {
         return Ok(());
     };
 
-    let expected_balance = U256::from(MINIMUM_BALANCE_FOR_WALLET);
+    let Some(l1_chain_id) = forge.get_chain_id().await? else {
+        return Ok(());
+    };
+    // Private networks are not known by chain ID, `--min-balance` is the way to adjust the threshold for them.
+    let l1_network = L1Network::from_chain_id(l1_chain_id);
+    let min_balance_from_env = std::env::var(MIN_BALANCE_ENV)
+        .ok()
+        .map(|value| {
+            parse_ether(&value).with_context(|| msg_invalid_min_balance_env(MIN_BALANCE_ENV))
+        })
+        .transpose()?;
+    let expected_balance =
+        minimum_balance(l1_network, forge.min_balance().or(min_balance_from_env));
+    let non_interactive = forge.non_interactive()
+        || std::env::var(NON_INTERACTIVE_ENV).is_ok_and(|value| value == "1" || value == "true");
+
     while let Some(balance) = forge.get_the_balance().await? {
         if balance >= expected_balance {
             return Ok(());
         }
 
-        let prompt_msg =
-            msg_address_doesnt_have_enough_money_prompt(&address, balance, expected_balance);
+        if let Some(l1_network) = l1_network.filter(L1Network::is_testnet) {
+            logger::info(msg_testnet_funding_hint(&address, l1_network));
+        }
+        match insufficient_balance_action(non_interactive, forge.ignore_balance_check()) {
+            InsufficientBalanceAction::Proceed => {
+                logger::warn(msg_insufficient_balance_ignored(
+                    &address,
+                    balance,
+                    expected_balance,
+                ));
+                return Ok(());
+            }
+            InsufficientBalanceAction::Fail => anyhow::bail!(msg_insufficient_balance_err(
+                &address,
+                l1_network,
+                l1_chain_id,
+                balance,
+                expected_balance
+            )),
+            InsufficientBalanceAction::Prompt => {}
+        }
+
+        let prompt_msg = msg_address_doesnt_have_enough_money_prompt(
+            &address,
+            l1_network,
+            l1_chain_id,
+            balance,
+            expected_balance,
+        );
         match zkstack_cli_common::PromptSelect::new(
             &prompt_msg,
             [MSG_CONTINUE, MSG_CHECK_BALANCE, MSG_EXIT],
@@ -58,3 +107,82 @@ pub async fn check_the_balance(forge: &ForgeScript) -> anyhow::Result<()> {
     }
     Ok(())
 }
+
+#[derive(Debug, PartialEq)]
+enum InsufficientBalanceAction {
+    Proceed,
+    Fail,
+    Prompt,
+}
+
+fn insufficient_balance_action(
+    non_interactive: bool,
+    ignore_balance_check: bool,
+) -> InsufficientBalanceAction {
+    if ignore_balance_check {
+        InsufficientBalanceAction::Proceed
+    } else if non_interactive {
+        InsufficientBalanceAction::Fail
+    } else {
+        InsufficientBalanceAction::Prompt
+    }
+}
+
+fn minimum_balance(l1_network: Option<L1Network>, min_balance_override: Option<U256>) -> U256 {
+    // Unknown networks are held to the mainnet threshold unless overridden.
+    min_balance_override.unwrap_or_else(|| {
+        l1_network
+            .unwrap_or(L1Network::Mainnet)
+            .minimum_deployment_balance()
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_minimum_balance_override() {
+        let network_default = L1Network::Sepolia.minimum_deployment_balance();
+        assert_eq!(
+            minimum_balance(Some(L1Network::Sepolia), None),
+            network_default
+        );
+        assert_eq!(
+            minimum_balance(None, None),
+            L1Network::Mainnet.minimum_deployment_balance()
+        );
+
+        let custom = parse_ether("0.1").unwrap();
+        assert_eq!(
+            minimum_balance(Some(L1Network::Sepolia), Some(custom)),
+            custom
+        );
+        assert_eq!(minimum_balance(None, Some(custom)), custom);
+        assert_eq!(
+            minimum_balance(Some(L1Network::Sepolia), Some(U256::zero())),
+            U256::zero()
+        );
+    }
+
+    #[test]
+    fn test_insufficient_balance_action() {
+        // Non-interactive mode must never end up in the prompt.
+        assert_eq!(
+            insufficient_balance_action(true, false),
+            InsufficientBalanceAction::Fail
+        );
+        assert_eq!(
+            insufficient_balance_action(true, true),
+            InsufficientBalanceAction::Proceed
+        );
+        assert_eq!(
+            insufficient_balance_action(false, true),
+            InsufficientBalanceAction::Proceed
+        );
+        assert_eq!(
+            insufficient_balance_action(false, false),
+            InsufficientBalanceAction::Prompt
+        );
+    }
+}
@@ -254,6 +254,14 @@ impl EcosystemPortsScanner {
         Ok(ecosystem_ports)
     }
 
+    /// Extracts port information from a single YAML file.
+    pub fn scan_file(shell: &Shell, file_path: &Path) -> Result<EcosystemPorts> {
+        let mut ecosystem_ports = EcosystemPorts::default();
+        Self::process_yaml_file(shell, file_path, &mut ecosystem_ports)
+            .context(format!("Error processing YAML file {:?}", file_path))?;
+        Ok(ecosystem_ports)
+    }
+
     /// Scans the given directory for YAML files in the immediate directory only (non-recursive).
     /// Processes each YAML file found and updates the EcosystemPorts accordingly.
     fn scan_yaml_files(